@@ -64,7 +64,7 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
             if !model.response.access_token.is_empty() {
                 let token = &model.response.access_token;
                 let key = &model.api_key;
-                let mut api = Client::new(token, key);
+                let api = Client::new(token, key);
                 let query = ListVideos::create_with_my_rating_like().build_query_parameters();
                 orders.perform_cmd(async move {
                     let res = api.video().list(query.get_query_params()).await;
@@ -79,7 +79,7 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
             if !model.response.access_token.is_empty() {
                 let token = &model.response.access_token;
                 let key = &model.api_key;
-                let mut api = Client::new(token, key);
+                let api = Client::new(token, key);
                 let query = ListVideos::create_with_chart_most_popular().build_query_parameters();
                 orders.perform_cmd(async move {
                     let res = api.video().list(query.get_query_params()).await;
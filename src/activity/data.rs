@@ -0,0 +1,165 @@
+use crate::resource::{ResourceId, Thumbnails};
+use serde::{Deserialize, Serialize};
+
+/// The activity data model https://developers.google.com/youtube/v3/docs/activities#resource
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    pub kind: String,
+    pub etag: String,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<ActivitySnippet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_details: Option<ActivityContentDetails>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySnippet {
+    pub published_at: String,
+    pub channel_id: String,
+    pub title: String,
+    pub description: String,
+    pub thumbnails: Option<Thumbnails>,
+    pub channel_title: String,
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    /// Shared by the activities resulting from the same action
+    pub group_id: Option<String>,
+}
+
+/// Type of the activity, the variant of `contentDetails` has the same name.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityType {
+    Upload,
+    Like,
+    Favorite,
+    Comment,
+    Subscription,
+    PlaylistItem,
+    Recommendation,
+    Bulletin,
+    Social,
+    ChannelItem,
+    PromotedItem,
+    /// Type unknown to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Details of the activity, keyed by its type.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityContentDetails {
+    Upload(ActivityUpload),
+    Like(ActivityResource),
+    Favorite(ActivityResource),
+    Comment(ActivityResource),
+    Subscription(ActivityResource),
+    PlaylistItem(ActivityPlaylistItem),
+    Recommendation(ActivityRecommendation),
+    Bulletin(ActivityResource),
+    Social(ActivitySocial),
+    ChannelItem(ActivityResource),
+    /// Details of a type unknown to this crate
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// Uploaded video.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityUpload {
+    pub video_id: String,
+}
+
+/// Resource the activity is about, the liked video or the subscribed channel for instance.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityResource {
+    pub resource_id: ResourceId,
+}
+
+/// Item added to a playlist.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityPlaylistItem {
+    pub resource_id: ResourceId,
+    pub playlist_id: String,
+    pub playlist_item_id: String,
+}
+
+/// Resource recommended to the user.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRecommendation {
+    pub resource_id: ResourceId,
+    /// `videoFavorited`, `videoLiked` or `videoWatched`
+    pub reason: Option<String>,
+    /// Resource that caused the recommendation
+    pub seed_resource_id: Option<ResourceId>,
+}
+
+/// Post on a social network.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySocial {
+    /// `facebook`, `googlePlus`, `twitter` or `unspecified`
+    #[serde(rename = "type")]
+    pub network: Option<String>,
+    pub resource_id: Option<ResourceId>,
+    pub author: Option<String>,
+    pub reference_url: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    #[test]
+    fn test_deserialize_content_details() {
+        let details: Vec<ActivityContentDetails> = serde_json::from_value(json!([
+            {"upload": {"videoId": "E6UTz_Doic8"}},
+            {"subscription": {"resourceId": {"kind": "youtube#channel", "channelId": "UC123"}}},
+            {"playlistItem": {
+                "resourceId": {"kind": "youtube#video", "videoId": "E6UTz_Doic8"},
+                "playlistId": "PL123",
+                "playlistItemId": "UEx1"
+            }},
+            {"promotedItem": {"videoId": "E6UTz_Doic8"}}
+        ]))
+        .unwrap();
+        assert_eq!(
+            details[0],
+            ActivityContentDetails::Upload(ActivityUpload {
+                video_id: "E6UTz_Doic8".to_string()
+            })
+        );
+        assert_eq!(
+            details[1],
+            ActivityContentDetails::Subscription(ActivityResource {
+                resource_id: ResourceId::channel("UC123")
+            })
+        );
+        assert!(
+            matches!(&details[2], ActivityContentDetails::PlaylistItem(item) if item.playlist_id == "PL123")
+        );
+        assert!(matches!(&details[3], ActivityContentDetails::Other(_)));
+    }
+    #[test]
+    fn test_deserialize_activity_type() {
+        let types: Vec<ActivityType> =
+            serde_json::from_value(json!(["upload", "channelItem", "newType"])).unwrap();
+        assert_eq!(
+            types,
+            vec![
+                ActivityType::Upload,
+                ActivityType::ChannelItem,
+                ActivityType::Other("newType".to_string())
+            ]
+        );
+    }
+}
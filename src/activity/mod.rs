@@ -0,0 +1,123 @@
+use crate::activity::data::Activity;
+use crate::activity::query::ListActivities;
+use crate::client::{Client, ClientError};
+use crate::response::{build_response, paginate_items, paginate_pages, YoutubeListResponse};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::activity::{data::*, query::*, ActivityEndPoint};
+}
+
+pub struct ActivityEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for ActivityEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        ActivityEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> ActivityEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("activities");
+        ActivityEndPoint { client, url }
+    }
+
+    /// Returns the channel activity events that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/activities/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<Activity>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the activities of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` activities were returned.
+    pub fn paginate(
+        &self,
+        query: ListActivities,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<Activity, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListActivities,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<Activity>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activity::data::{ActivityContentDetails, ActivityType};
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    #[test]
+    fn test_list_channel_activities() {
+        let activity = json!({
+            "kind": "youtube#activity",
+            "etag": "etag",
+            "id": "a1",
+            "snippet": {
+                "publishedAt": "2021-05-01T10:00:00Z",
+                "channelId": "UC123",
+                "title": "New video",
+                "description": "",
+                "channelTitle": "Rust",
+                "type": "upload"
+            },
+            "contentDetails": {"upload": {"videoId": "E6UTz_Doic8"}}
+        });
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "activities",
+            &[("channelId", "UC123")],
+            json(
+                200,
+                fixtures::list("youtube#activityListResponse", vec![activity], None),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListActivities::create_with_channel_id("UC123").build_query_parameters();
+        let activities = block_on(client.activities().list(query.get_query_params())).unwrap();
+        let activity = &activities.items[0];
+        assert_eq!(
+            activity.snippet.as_ref().unwrap().activity_type,
+            ActivityType::Upload
+        );
+        match &activity.content_details {
+            Some(ActivityContentDetails::Upload(upload)) => {
+                assert_eq!(upload.video_id, "E6UTz_Doic8")
+            }
+            details => panic!("unexpected content details {:?}", details),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the activity resource, see https://developers.google.com/youtube/v3/docs/activities#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityPart {
+    Snippet,
+    ContentDetails,
+    Id,
+}
+
+impl Part for ActivityPart {
+    const ALL: &'static [Self] = &[
+        ActivityPart::Snippet,
+        ActivityPart::ContentDetails,
+        ActivityPart::Id,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ActivityPart::Snippet => "snippet",
+            ActivityPart::ContentDetails => "contentDetails",
+            ActivityPart::Id => "id",
+        }
+    }
+}
+
+/// Filter selecting the listed activities, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+pub enum ActivityFilter {
+    /// Activities of a channel
+    ChannelId(String),
+    /// Activities of the authenticated user
+    #[default]
+    Mine,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListActivities {
+    /// Activity resource properties that the API response will include
+    part: PartSet<ActivityPart>,
+    /// Selects the activities to list
+    filter: ActivityFilter,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Only returns the activities at or after this RFC 3339 date-time
+    published_after: Option<String>,
+    /// Only returns the activities before this RFC 3339 date-time
+    published_before: Option<String>,
+    /// Returns the activities for this ISO 3166-1 alpha-2 country code
+    region_code: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListActivities {
+    /// Create with the given filter
+    pub fn new(filter: ActivityFilter) -> ListActivities {
+        ListActivities::default().parse_filter(filter)
+    }
+    /// Create to list the activities of the authenticated user
+    pub fn create_with_mine() -> ListActivities {
+        ListActivities::new(ActivityFilter::Mine)
+    }
+    /// Create to list the activities of a channel
+    pub fn create_with_channel_id(channel_id: &str) -> ListActivities {
+        ListActivities::new(ActivityFilter::ChannelId(channel_id.to_string()))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<ActivityPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: ActivityFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse published_after to its field
+    pub fn parse_published_after(mut self, published_after: &str) -> Self {
+        self.published_after = Some(published_after.to_string());
+        self
+    }
+    /// Parse published_before to its field
+    pub fn parse_published_before(mut self, published_before: &str) -> Self {
+        self.published_before = Some(published_before.to_string());
+        self
+    }
+    /// Parse region_code to its field
+    pub fn parse_region_code(mut self, region_code: &str) -> Self {
+        self.region_code = Some(region_code.to_string());
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<ActivityPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &ActivityFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            ActivityFilter::ChannelId(channel_id) => query.push("channelId", channel_id),
+            ActivityFilter::Mine => query.push("mine", true),
+        };
+        self.query_params = query
+            .push_opt("publishedAfter", self.published_after.as_ref())
+            .push_opt("publishedBefore", self.published_before.as_ref())
+            .push_opt("regionCode", self.region_code.as_ref())
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_activities() {
+        let activities = ListActivities::create_with_channel_id("UC123")
+            .parse_published_after("2021-05-01T00:00:00Z")
+            .parse_published_before("2021-06-01T00:00:00Z")
+            .parse_region_code("FR")
+            .parse_max_results(20)
+            .build_query_parameters();
+        assert_eq!(
+            activities.get_query_params(),
+            "part=snippet,contentDetails,id&channelId=UC123\
+            &publishedAfter=2021-05-01T00%3A00%3A00Z&publishedBefore=2021-06-01T00%3A00%3A00Z\
+            &regionCode=FR&maxResults=20"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_my_activities() {
+        let activities = ListActivities::create_with_mine()
+            .parse_part(PartSet::new().with(ActivityPart::ContentDetails))
+            .build_query_parameters();
+        assert_eq!(
+            activities.get_query_params(),
+            "part=contentDetails&mine=true"
+        )
+    }
+}
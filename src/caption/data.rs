@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+/// The caption data model https://developers.google.com/youtube/v3/docs/captions#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Caption {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting a caption track
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<CaptionSnippet>,
+}
+
+impl Caption {
+    /// Create the metadata of a new caption track.
+    pub fn new(video_id: &str, language: &str, name: &str) -> Self {
+        Caption {
+            snippet: Some(CaptionSnippet {
+                video_id: video_id.to_string(),
+                language: language.to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+    /// Set whether the track is a draft, drafts are not shown to the viewers.
+    pub fn with_draft(mut self, is_draft: bool) -> Self {
+        self.snippet.get_or_insert_with(Default::default).is_draft = Some(is_draft);
+        self
+    }
+}
+
+/// The read-only properties are not sent back when inserting or updating.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptionSnippet {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub video_id: String,
+    #[serde(skip_serializing)]
+    pub last_updated: Option<String>,
+    #[serde(skip_serializing)]
+    pub track_kind: Option<TrackKind>,
+    /// BCP-47 language tag
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub language: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// `primary`, `commentary`, `descriptive` or `unknown`
+    #[serde(skip_serializing)]
+    pub audio_track_type: Option<String>,
+    #[serde(skip_serializing)]
+    pub is_cc: Option<bool>,
+    #[serde(skip_serializing)]
+    pub is_large: Option<bool>,
+    #[serde(skip_serializing)]
+    pub is_easy_reader: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_draft: Option<bool>,
+    #[serde(skip_serializing)]
+    pub is_auto_synced: Option<bool>,
+    #[serde(skip_serializing)]
+    pub status: Option<CaptionStatus>,
+    /// Set when the status is `failed`
+    #[serde(skip_serializing)]
+    pub failure_reason: Option<String>,
+}
+
+/// Kind of caption track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TrackKind {
+    /// Automatic speech recognition track
+    #[serde(rename = "asr", alias = "ASR")]
+    Asr,
+    #[serde(rename = "forced")]
+    Forced,
+    #[serde(rename = "standard")]
+    Standard,
+}
+
+/// Processing status of a caption track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptionStatus {
+    Failed,
+    Serving,
+    Syncing,
+}
+
+/// Response of captions.list, which is neither paginated nor has a `pageInfo`
+/// https://developers.google.com/youtube/v3/docs/captions/list#response
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptionListResponse {
+    pub kind: String,
+    pub etag: String,
+    pub items: Vec<Caption>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_draft_update_skips_empty_fields() {
+        let caption = Caption {
+            id: "c1".to_string(),
+            ..Default::default()
+        }
+        .with_draft(false);
+        assert_eq!(
+            serde_json::to_value(&caption).unwrap(),
+            json!({"id": "c1", "snippet": {"isDraft": false}})
+        );
+    }
+}
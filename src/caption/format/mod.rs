@@ -0,0 +1,440 @@
+//! Parse and emit caption tracks in the SubRip, WebVTT, SubViewer and TTML formats.
+//!
+//! Every format is read into the same list of cues, so a track can be converted, shifted or
+//! edited before being uploaded again.
+
+use crate::caption::query::CaptionFormat;
+use core::fmt;
+use std::time::Duration;
+
+mod sbv;
+mod srt;
+mod ttml;
+mod vtt;
+
+/// Text displayed between two instants of the video.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cue {
+    pub start: Duration,
+    pub end: Duration,
+    /// Plain text, lines are separated by `\n`
+    pub text: String,
+    pub style: CueStyle,
+}
+
+impl Cue {
+    pub fn new(start: Duration, end: Duration, text: &str) -> Self {
+        Cue {
+            start,
+            end,
+            text: text.to_string(),
+            style: CueStyle::default(),
+        }
+    }
+}
+
+/// Styling hints applying to the whole cue, the formats unable to carry one of them ignore it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CueStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Color name or `#rrggbb` value
+    pub color: Option<String>,
+    /// WebVTT cue settings such as `line:0 align:start`
+    pub settings: Option<String>,
+}
+
+/// Cues of a caption track, in the order they were read.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CaptionTrack {
+    pub cues: Vec<Cue>,
+}
+
+impl CaptionTrack {
+    pub fn new(cues: Vec<Cue>) -> Self {
+        CaptionTrack { cues }
+    }
+
+    /// Parse a track, SCC is not supported.
+    pub fn parse(format: CaptionFormat, input: &str) -> Result<Self, FormatError> {
+        let input = input.trim_start_matches('\u{feff}');
+        let cues = match format {
+            CaptionFormat::Srt => srt::parse(input)?,
+            CaptionFormat::Vtt => vtt::parse(input)?,
+            CaptionFormat::Sbv => sbv::parse(input)?,
+            CaptionFormat::Ttml => ttml::parse(input)?,
+            CaptionFormat::Scc => return Err(FormatError::unsupported(format)),
+        };
+        Ok(CaptionTrack { cues })
+    }
+
+    /// Parse a downloaded track, which must be UTF-8.
+    pub fn parse_bytes(format: CaptionFormat, input: &[u8]) -> Result<Self, FormatError> {
+        let input = std::str::from_utf8(input)
+            .map_err(|e| FormatError::new(None, format!("invalid UTF-8: {}", e)))?;
+        CaptionTrack::parse(format, input)
+    }
+
+    /// Write the track in the given format, SCC is not supported.
+    pub fn emit(&self, format: CaptionFormat) -> Result<String, FormatError> {
+        match format {
+            CaptionFormat::Srt => Ok(srt::emit(&self.cues)),
+            CaptionFormat::Vtt => Ok(vtt::emit(&self.cues)),
+            CaptionFormat::Sbv => Ok(sbv::emit(&self.cues)),
+            CaptionFormat::Ttml => Ok(ttml::emit(&self.cues)),
+            CaptionFormat::Scc => Err(FormatError::unsupported(format)),
+        }
+    }
+
+    /// Move every cue by `millis` milliseconds, later when positive and earlier when negative.
+    /// Timings are clamped to the start of the video.
+    pub fn shift(&mut self, millis: i64) {
+        for cue in self.cues.iter_mut() {
+            cue.start = shift_duration(cue.start, millis);
+            cue.end = shift_duration(cue.end, millis);
+        }
+    }
+}
+
+/// Error raised when a track is malformed or its format not supported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl FormatError {
+    fn new(line: Option<usize>, message: impl Into<String>) -> Self {
+        FormatError {
+            line,
+            message: message.into(),
+        }
+    }
+    fn unsupported(format: CaptionFormat) -> Self {
+        FormatError::new(
+            None,
+            format!("the {} format is not supported", format.as_str()),
+        )
+    }
+    /// Line of the input where the error was found, starting at 1.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+fn shift_duration(duration: Duration, millis: i64) -> Duration {
+    let offset = Duration::from_millis(millis.unsigned_abs());
+    if millis >= 0 {
+        duration + offset
+    } else {
+        duration.checked_sub(offset).unwrap_or_default()
+    }
+}
+
+/// Group the lines separated by blank lines, with the number of their first line.
+fn split_blocks(input: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| (index + 1, Vec::new()))
+                .1
+                .push(line);
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+/// Parse `[hh:]mm:ss[.,]fff`, hours may have any number of digits.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let mut fields = timestamp.trim().rsplit(':');
+    let seconds = fields.next()?;
+    let minutes: u64 = fields.next()?.parse().ok()?;
+    let hours: u64 = match fields.next() {
+        Some(hours) => hours.parse().ok()?,
+        None => 0,
+    };
+    if fields.next().is_some() || minutes > 59 {
+        return None;
+    }
+    let mut seconds = seconds.splitn(2, ['.', ',']);
+    let whole: u64 = seconds.next()?.parse().ok()?;
+    let millis = match seconds.next() {
+        Some(fraction) => parse_fraction(fraction)?,
+        None => 0,
+    };
+    if whole > 59 {
+        return None;
+    }
+    Some(Duration::from_millis(
+        ((hours * 60 + minutes) * 60 + whole) * 1000 + millis,
+    ))
+}
+
+/// Milliseconds of a decimal fraction of second, `5` being 500.
+fn parse_fraction(fraction: &str) -> Option<u64> {
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+    digits.parse().ok()
+}
+
+/// Write `hh:mm:ss.fff`, hours are padded to `hour_width` digits.
+fn format_timestamp(duration: Duration, separator: char, hour_width: usize) -> String {
+    let millis = duration.as_millis();
+    format!(
+        "{:0width$}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        separator,
+        millis % 1000,
+        width = hour_width
+    )
+}
+
+/// Remove the inline tags of SubRip and WebVTT texts, keeping them as hints of the cue style.
+/// Unknown tags are kept as text, SubRip having no way to escape a literal `<`.
+fn strip_tags(text: &str, style: &mut CueStyle) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('<') {
+        let close = match rest[open..].find('>') {
+            Some(close) => open + close,
+            None => break,
+        };
+        if apply_tag(&rest[open + 1..close], style) {
+            plain.push_str(&rest[..open]);
+            rest = &rest[close + 1..];
+        } else {
+            plain.push_str(&rest[..=open]);
+            rest = &rest[open + 1..];
+        }
+    }
+    plain.push_str(rest);
+    plain
+}
+
+/// Apply the style of a tag, returns whether the tag is known.
+fn apply_tag(tag: &str, style: &mut CueStyle) -> bool {
+    let tag = tag.trim();
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag.trim()),
+        None => (false, tag),
+    };
+    let name = tag
+        .split(|c: char| c.is_whitespace() || c == '.')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    // WebVTT also has karaoke timestamps and tags without style.
+    let known = matches!(
+        name.as_str(),
+        "b" | "i" | "u" | "font" | "c" | "v" | "lang" | "ruby" | "rt"
+    ) || parse_timestamp(tag).is_some();
+    if !known || closing {
+        return known;
+    }
+    match name.as_str() {
+        "b" => style.bold = true,
+        "i" => style.italic = true,
+        "u" => style.underline = true,
+        "font" => {
+            if let Some(color) = attribute(tag, "color") {
+                style.color = Some(color);
+            }
+        }
+        "c" => {
+            if let Some(class) = tag.split('.').nth(1) {
+                let class = class.split_whitespace().next().unwrap_or_default();
+                style.color = Some(class.to_string());
+            }
+        }
+        _ => {}
+    }
+    true
+}
+
+/// Value of an attribute of a tag, quoted or not.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &tag[start..];
+    let value = match value.chars().next()? {
+        quote @ '"' | quote @ '\'' => value[1..].split(quote).next()?,
+        _ => value.split_whitespace().next()?,
+    };
+    Some(value.to_string())
+}
+
+/// Decode the XML and WebVTT character references.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            "lrm" => Some('\u{200e}'),
+            "rlm" => Some('\u{200f}'),
+            _ => {
+                let code = if let Some(hex) = name.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(decimal) = name.strip_prefix('#') {
+                    decimal.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Escape the characters having a meaning in WebVTT and XML texts.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Wrap the text in the bold, italic and underline tags shared by SubRip and WebVTT.
+fn wrap_tags(text: String, style: &CueStyle) -> String {
+    let mut text = text;
+    for (enabled, tag) in [
+        (style.underline, "u"),
+        (style.italic, "i"),
+        (style.bold, "b"),
+    ] {
+        if enabled {
+            text = format!("<{tag}>{}</{tag}>", text, tag = tag);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_parse_timestamps() {
+        assert_eq!(parse_timestamp("01:02:03,456"), Some(millis(3_723_456)));
+        assert_eq!(parse_timestamp("02:03.4"), Some(millis(123_400)));
+        assert_eq!(parse_timestamp("100:00:00.000"), Some(millis(360_000_000)));
+        assert_eq!(parse_timestamp("00:60:00.000"), None);
+        assert_eq!(parse_timestamp("00:00:00.0a0"), None);
+        assert_eq!(parse_timestamp("12"), None);
+        assert_eq!(format_timestamp(millis(3_723_456), ',', 2), "01:02:03,456");
+        assert_eq!(format_timestamp(millis(3_456), '.', 1), "0:00:03.456");
+    }
+    #[test]
+    fn test_convert_srt_to_vtt_and_shift() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,500\n<i>Hello</i> & welcome\n\n\
+                   2\n00:00:03,000 --> 00:00:04,000\nSecond\nline\n";
+        let mut track = CaptionTrack::parse(CaptionFormat::Srt, srt).unwrap();
+        track.shift(-1500);
+        assert_eq!(track.cues[0].start, Duration::from_secs(0));
+        assert_eq!(track.cues[1].start, millis(1500));
+        assert_eq!(
+            track.emit(CaptionFormat::Vtt).unwrap(),
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:01.000\n<i>Hello &amp; welcome</i>\n\n\
+             00:00:01.500 --> 00:00:02.500\nSecond\nline\n\n"
+        );
+    }
+    #[test]
+    fn test_convert_every_format() {
+        let mut cue = Cue::new(millis(1000), millis(2000), "Bold\nline");
+        cue.style.bold = true;
+        let track = CaptionTrack::new(vec![cue, Cue::new(millis(2000), millis(3000), "a < b")]);
+        for format in [
+            CaptionFormat::Srt,
+            CaptionFormat::Vtt,
+            CaptionFormat::Sbv,
+            CaptionFormat::Ttml,
+        ] {
+            let emitted = track.emit(format).unwrap();
+            let parsed = CaptionTrack::parse(format, &emitted).unwrap();
+            assert_eq!(parsed.cues.len(), 2, "{:?}", format);
+            assert_eq!(parsed.cues[0].text, "Bold\nline", "{:?}", format);
+            assert_eq!(parsed.cues[1].text, "a < b", "{:?}", format);
+            assert_eq!(parsed.cues[1].end, millis(3000), "{:?}", format);
+            assert_eq!(
+                parsed.cues[0].style.bold,
+                format != CaptionFormat::Sbv,
+                "{:?}",
+                format
+            );
+        }
+    }
+    #[test]
+    fn test_convert_colors_through_vtt() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,000\n<font color=\"#ff0000\">Red</font>\n";
+        let ttml = "<tt xmlns=\"http://www.w3.org/ns/ttml\" \
+                    xmlns:tts=\"http://www.w3.org/ns/ttml#styling\"><body><div>\
+                    <p begin=\"00:00:01.000\" end=\"00:00:02.000\" \
+                    tts:color=\"#00ff00\">Green</p>\
+                    </div></body></tt>";
+        for (format, input, color) in [
+            (CaptionFormat::Srt, srt, "#ff0000"),
+            (CaptionFormat::Ttml, ttml, "#00ff00"),
+        ] {
+            let track = CaptionTrack::parse(format, input).unwrap();
+            let vtt = track.emit(CaptionFormat::Vtt).unwrap();
+            let parsed = CaptionTrack::parse(CaptionFormat::Vtt, &vtt).unwrap();
+            assert_eq!(parsed.cues[0].style.color.as_deref(), Some(color), "{}", vtt);
+        }
+    }
+    #[test]
+    fn test_scc_is_not_supported() {
+        let error = CaptionTrack::default()
+            .emit(CaptionFormat::Scc)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "the scc format is not supported");
+    }
+}
@@ -0,0 +1,53 @@
+//! SubViewer, `h:mm:ss.fff,h:mm:ss.fff` timings followed by plain text.
+
+use super::{format_timestamp, parse_timestamp, split_blocks};
+use super::{Cue, FormatError};
+
+pub(super) fn parse(input: &str) -> Result<Vec<Cue>, FormatError> {
+    split_blocks(input)
+        .into_iter()
+        .map(|(line, lines)| {
+            let mut timing = lines[0].splitn(2, ',');
+            let start = timing.next().and_then(parse_timestamp);
+            let end = timing.next().and_then(parse_timestamp);
+            match (start, end) {
+                (Some(start), Some(end)) => Ok(Cue::new(start, end, &lines[1..].join("\n"))),
+                _ => Err(FormatError::new(Some(line), "invalid cue timing")),
+            }
+        })
+        .collect()
+}
+
+pub(super) fn emit(cues: &[Cue]) -> String {
+    cues.iter()
+        .map(|cue| {
+            format!(
+                "{},{}\n{}\n\n",
+                format_timestamp(cue.start, '.', 1),
+                format_timestamp(cue.end, '.', 1),
+                cue.text
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_sbv() {
+        let input = "0:00:00.599,0:00:04.160\n>> ALICE: Hi, my name is Alice\n\n\
+                     0:00:04.160,0:00:06.770\nand I work at Google\n";
+        let cues = parse(input).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start, Duration::from_millis(599));
+        assert_eq!(cues[0].text, ">> ALICE: Hi, my name is Alice");
+        assert_eq!(
+            emit(&cues[1..]),
+            "0:00:04.160,0:00:06.770\nand I work at Google\n\n"
+        );
+        assert_eq!(parse("0:00:00.599\ntext").unwrap_err().line(), Some(1));
+    }
+}
@@ -0,0 +1,97 @@
+//! SubRip, numbered cues with `hh:mm:ss,fff` timings and HTML-like tags.
+
+use super::{format_timestamp, parse_timestamp, split_blocks, strip_tags, wrap_tags};
+use super::{Cue, CueStyle, FormatError};
+
+pub(super) fn parse(input: &str) -> Result<Vec<Cue>, FormatError> {
+    split_blocks(input)
+        .into_iter()
+        .map(|(line, lines)| {
+            // The index is optional since many files do not number their cues.
+            let timing = match lines.iter().position(|l| l.contains("-->")) {
+                Some(position) if position <= 1 => position,
+                _ => return Err(FormatError::new(Some(line), "missing cue timing")),
+            };
+            let (start, end) = parse_timing(lines[timing])
+                .ok_or_else(|| FormatError::new(Some(line + timing), "invalid cue timing"))?;
+            let mut style = CueStyle::default();
+            let text = strip_tags(&lines[timing + 1..].join("\n"), &mut style);
+            Ok(Cue {
+                start,
+                end,
+                text,
+                style,
+            })
+        })
+        .collect()
+}
+
+fn parse_timing(timing: &str) -> Option<(std::time::Duration, std::time::Duration)> {
+    let mut split = timing.splitn(2, "-->");
+    let start = parse_timestamp(split.next()?)?;
+    // Some files add the coordinates of the text after the end.
+    let end = parse_timestamp(split.next()?.split_whitespace().next()?)?;
+    Some((start, end))
+}
+
+pub(super) fn emit(cues: &[Cue]) -> String {
+    cues.iter()
+        .enumerate()
+        .map(|(index, cue)| {
+            let mut text = wrap_tags(cue.text.clone(), &cue.style);
+            if let Some(color) = &cue.style.color {
+                text = format!("<font color=\"{}\">{}</font>", color, text);
+            }
+            format!(
+                "{}\n{} --> {}\n{}\n\n",
+                index + 1,
+                format_timestamp(cue.start, ',', 2),
+                format_timestamp(cue.end, ',', 2),
+                text
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_srt() {
+        let input = "\u{31}\r\n00:00:01,000 --> 00:00:02,000 X1:10 X2:20\r\n\
+                     <font color=\"#ff0000\"><b>Red</b></font>\r\n\r\n\r\n\
+                     00:00:02,000 --> 00:00:03,000\r\nNo index\r\n";
+        let cues = parse(input).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].end, Duration::from_secs(2));
+        assert_eq!(cues[0].text, "Red");
+        assert!(cues[0].style.bold);
+        assert_eq!(cues[0].style.color.as_deref(), Some("#ff0000"));
+        assert_eq!(cues[1].text, "No index");
+        assert_eq!(
+            emit(&cues[..1]),
+            "1\n00:00:01,000 --> 00:00:02,000\n<font color=\"#ff0000\"><b>Red</b></font>\n\n"
+        );
+    }
+    #[test]
+    fn test_keep_unknown_tags_as_text() {
+        let cues = parse("1\n00:00:01,000 --> 00:00:02,000\n1 < 2 > 0 <i>x</i>\n").unwrap();
+        assert_eq!(cues[0].text, "1 < 2 > 0 x");
+        assert!(cues[0].style.italic);
+        let cues = parse(&emit(&cues)).unwrap();
+        assert_eq!(cues[0].text, "1 < 2 > 0 x");
+
+        let cues = parse("1\n00:00:01,000 --> 00:00:02,000\n1 < 2 > 0\n").unwrap();
+        assert_eq!(parse(&emit(&cues)).unwrap(), cues);
+    }
+    #[test]
+    fn test_parse_srt_reports_line() {
+        let error =
+            parse("1\n00:00:01,000 --> 00:00:02,000\nOk\n\n2\n00:00:03 --> soon\nKo").unwrap_err();
+        assert_eq!(error.line(), Some(6));
+        let error = parse("1\n2\n3\n00:00:01,000 --> 00:00:02,000").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: missing cue timing");
+    }
+}
@@ -0,0 +1,379 @@
+//! Timed Text Markup Language, the `p` elements of the body are the cues.
+//!
+//! Only the subset used by caption files is read: the styles of the head, the `begin`, `end`
+//! and `dur` timings of the paragraphs, their spans and line breaks.
+
+use super::{decode_entities, escape, format_timestamp, parse_fraction, parse_timestamp};
+use super::{Cue, CueStyle, FormatError};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Frame rate assumed for the `hh:mm:ss:ff` timings.
+const FRAME_RATE: u64 = 30;
+
+#[derive(Debug)]
+enum Token<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        empty: bool,
+    },
+    End,
+    Text(&'a str),
+}
+
+/// Split the document into tags and texts, with the byte offset of each of them.
+fn tokenize(input: &str) -> Result<Vec<(usize, Token<'_>)>, FormatError> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    while offset < input.len() {
+        let rest = &input[offset..];
+        let skipped = [("<!--", "-->"), ("<?", "?>"), ("<!", ">")]
+            .iter()
+            .find(|(open, _)| rest.starts_with(open));
+        if let Some((_, close)) = skipped {
+            let end = rest
+                .find(close)
+                .ok_or_else(|| error(input, offset, "unterminated markup"))?;
+            offset += end + close.len();
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest).ok_or_else(|| error(input, offset, "unterminated tag"))?;
+            let tag = &rest[1..end];
+            let token = if tag.starts_with('/') {
+                Token::End
+            } else {
+                parse_tag(tag).ok_or_else(|| error(input, offset, "malformed tag"))?
+            };
+            tokens.push((offset, token));
+            offset += end + 1;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push((offset, Token::Text(&rest[..end])));
+            offset += end;
+        }
+    }
+    Ok(tokens)
+}
+
+/// Offset of the `>` closing the tag, ignoring those quoted in the attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_tag(tag: &str) -> Option<Token<'_>> {
+    let empty = tag.ends_with('/');
+    let tag = tag.trim_end_matches('/');
+    let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+    let mut attributes = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let equal = rest.find('=')?;
+        let key = rest[..equal].trim();
+        let value = rest[equal + 1..].trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let close = value[1..].find(quote)? + 1;
+        attributes.push((local_name(key), decode_entities(&value[1..close])));
+        rest = value[close + 1..].trim_start();
+    }
+    Some(Token::Start {
+        name: local_name(&tag[..name_end]),
+        attributes,
+        empty,
+    })
+}
+
+/// Name without its namespace prefix, `tts:color` being `color`.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn error(input: &str, offset: usize, message: &str) -> FormatError {
+    let line = input[..offset].matches('\n').count() + 1;
+    FormatError::new(Some(line), message)
+}
+
+pub(super) fn parse(input: &str) -> Result<Vec<Cue>, FormatError> {
+    let mut styles: HashMap<String, CueStyle> = HashMap::new();
+    let mut cues = Vec::new();
+    // Style of the enclosing elements, to restore it at their end.
+    let mut stack: Vec<CueStyle> = Vec::new();
+    let mut style = CueStyle::default();
+    let mut cue: Option<Cue> = None;
+    // Depth of the paragraph being read, its cue is complete when the stack is back to it.
+    let mut paragraph_depth = None;
+
+    for (offset, token) in tokenize(input)? {
+        match token {
+            Token::Start {
+                name,
+                attributes,
+                empty,
+            } => {
+                let mut element_style = style.clone();
+                apply_attributes(&mut element_style, &attributes, &styles);
+                match name {
+                    "style" => {
+                        if let Some((_, id)) = attributes.iter().find(|(key, _)| *key == "id") {
+                            let mut named = CueStyle::default();
+                            apply_attributes(&mut named, &attributes, &styles);
+                            styles.insert(id.clone(), named);
+                        }
+                    }
+                    "p" => {
+                        let (start, end) = parse_timing(&attributes)
+                            .map_err(|message| error(input, offset, &message))?;
+                        let paragraph = Cue {
+                            start,
+                            end,
+                            text: String::new(),
+                            style: element_style.clone(),
+                        };
+                        if empty {
+                            cues.push(paragraph);
+                        } else {
+                            cue = Some(paragraph);
+                            paragraph_depth = Some(stack.len());
+                        }
+                    }
+                    "span" => {
+                        if let Some(cue) = cue.as_mut() {
+                            merge(&mut cue.style, &element_style);
+                        }
+                    }
+                    "br" => {
+                        if let Some(cue) = cue.as_mut() {
+                            cue.text.push('\n');
+                        }
+                    }
+                    _ => {}
+                }
+                if !empty {
+                    stack.push(std::mem::replace(&mut style, element_style));
+                }
+            }
+            Token::End => {
+                style = stack.pop().unwrap_or_default();
+                if paragraph_depth == Some(stack.len()) {
+                    cues.extend(cue.take().map(finish));
+                    paragraph_depth = None;
+                }
+            }
+            Token::Text(text) => {
+                if let Some(cue) = cue.as_mut() {
+                    // Line breaks only come from `br`, the others are white spaces.
+                    let text = decode_entities(text).replace(['\n', '\r', '\t'], " ");
+                    cue.text.push_str(&text);
+                }
+            }
+        }
+    }
+    Ok(cues)
+}
+
+/// Collapse the white spaces of the text as XML does by default.
+fn finish(mut cue: Cue) -> Cue {
+    cue.text = cue
+        .text
+        .split('\n')
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    cue
+}
+
+fn merge(style: &mut CueStyle, other: &CueStyle) {
+    style.bold |= other.bold;
+    style.italic |= other.italic;
+    style.underline |= other.underline;
+    if other.color.is_some() {
+        style.color = other.color.clone();
+    }
+}
+
+fn apply_attributes(
+    style: &mut CueStyle,
+    attributes: &[(&str, String)],
+    styles: &HashMap<String, CueStyle>,
+) {
+    // Referenced styles come first, the inline attributes override them.
+    if let Some((_, references)) = attributes.iter().find(|(key, _)| *key == "style") {
+        for reference in references.split_whitespace() {
+            if let Some(referenced) = styles.get(reference) {
+                merge(style, referenced);
+            }
+        }
+    }
+    for (key, value) in attributes {
+        match *key {
+            "fontWeight" => style.bold = value == "bold",
+            "fontStyle" => style.italic = value == "italic" || value == "oblique",
+            "textDecoration" => style.underline = value.contains("underline"),
+            "color" => style.color = Some(value.clone()),
+            _ => {}
+        }
+    }
+}
+
+fn parse_timing(attributes: &[(&str, String)]) -> Result<(Duration, Duration), String> {
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let time =
+        |value: &str| parse_time(value).ok_or_else(|| format!("invalid time expression {}", value));
+    let start = time(attribute("begin").ok_or("missing begin")?)?;
+    let end = match (attribute("end"), attribute("dur")) {
+        (Some(end), _) => time(end)?,
+        (None, Some(duration)) => start + time(duration)?,
+        (None, None) => return Err("missing end".to_string()),
+    };
+    Ok((start, end))
+}
+
+/// Parse a clock time, `hh:mm:ss.fff` or `hh:mm:ss:ff`, or an offset time such as `1.5s`.
+fn parse_time(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if value.matches(':').count() == 3 {
+        let (clock, frames) = value.rsplit_once(':')?;
+        let frames: u64 = frames.parse().ok()?;
+        return Some(parse_timestamp(clock)? + Duration::from_millis(frames * 1000 / FRAME_RATE));
+    }
+    if value.contains(':') {
+        return parse_timestamp(value);
+    }
+    let unit_start = value.find(|c: char| c.is_ascii_alphabetic())?;
+    let (number, unit) = value.split_at(unit_start);
+    let mut number = number.splitn(2, '.');
+    let whole: u64 = number.next()?.parse().ok()?;
+    let fraction = match number.next() {
+        Some(fraction) => parse_fraction(fraction)?,
+        None => 0,
+    };
+    let millis = whole * 1000 + fraction;
+    let millis = match unit {
+        "h" => millis * 3600,
+        "m" => millis * 60,
+        "s" => millis,
+        "ms" => millis / 1000,
+        "f" => millis / FRAME_RATE,
+        _ => return None,
+    };
+    Some(Duration::from_millis(millis))
+}
+
+pub(super) fn emit(cues: &[Cue]) -> String {
+    let paragraphs: String = cues
+        .iter()
+        .map(|cue| {
+            let mut attributes = String::new();
+            if cue.style.bold {
+                attributes.push_str(" tts:fontWeight=\"bold\"");
+            }
+            if cue.style.italic {
+                attributes.push_str(" tts:fontStyle=\"italic\"");
+            }
+            if cue.style.underline {
+                attributes.push_str(" tts:textDecoration=\"underline\"");
+            }
+            if let Some(color) = &cue.style.color {
+                attributes.push_str(&format!(" tts:color=\"{}\"", escape(color)));
+            }
+            let text = cue
+                .text
+                .split('\n')
+                .map(escape)
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            format!(
+                "      <p begin=\"{}\" end=\"{}\"{}>{}</p>\n",
+                format_timestamp(cue.start, '.', 2),
+                format_timestamp(cue.end, '.', 2),
+                attributes,
+                text
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <tt xmlns=\"http://www.w3.org/ns/ttml\" xmlns:tts=\"http://www.w3.org/ns/ttml#styling\">\n\
+         \x20 <body>\n\
+         \x20   <div>\n\
+         {}\
+         \x20   </div>\n\
+         \x20 </body>\n\
+         </tt>\n",
+        paragraphs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ttml() {
+        let input = r#"<?xml version="1.0" encoding="utf-8" ?>
+<tt xml:lang="en" xmlns="http://www.w3.org/ns/ttml" xmlns:tts="http://www.w3.org/ns/ttml#styling">
+  <head>
+    <styling>
+      <style xml:id="s1" tts:fontStyle="italic" tts:color="yellow"/>
+    </styling>
+  </head>
+  <body>
+    <!-- generated > by hand -->
+    <div>
+      <p begin="00:00:00.599" end="00:00:04.160" style="s1">Hi, my name
+        is Alice<br/>and I &amp; Bob</p>
+      <p begin="1.5s" dur="500ms"><span tts:fontWeight="bold">Bold</span> text</p>
+      <p begin="00:00:03:15" end="00:00:04:00"/>
+    </div>
+  </body>
+</tt>"#;
+        let cues = parse(input).unwrap();
+        assert_eq!(cues.len(), 3);
+        assert_eq!(cues[0].start, Duration::from_millis(599));
+        assert_eq!(cues[0].text, "Hi, my name is Alice\nand I & Bob");
+        assert!(cues[0].style.italic);
+        assert_eq!(cues[0].style.color.as_deref(), Some("yellow"));
+        assert_eq!(cues[1].start, Duration::from_millis(1500));
+        assert_eq!(cues[1].end, Duration::from_secs(2));
+        assert_eq!(cues[1].text, "Bold text");
+        assert!(cues[1].style.bold && !cues[1].style.italic);
+        assert_eq!(cues[2].start, Duration::from_millis(3500));
+    }
+    #[test]
+    fn test_parse_ttml_reports_line() {
+        let error = parse("<tt>\n<body>\n<p begin=\"soon\" end=\"1s\">Ko</p>").unwrap_err();
+        assert_eq!(error.to_string(), "line 3: invalid time expression soon");
+        let error = parse("<tt>\n<p begin=\"1s\">Ko</p>").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: missing end");
+        assert_eq!(parse("<tt><p").unwrap_err().line(), Some(1));
+    }
+    #[test]
+    fn test_emit_ttml() {
+        let mut cue = Cue::new(Duration::from_secs(1), Duration::from_secs(2), "a & b\nc");
+        cue.style.italic = true;
+        assert_eq!(
+            emit(&[cue]),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <tt xmlns=\"http://www.w3.org/ns/ttml\" xmlns:tts=\"http://www.w3.org/ns/ttml#styling\">\n\
+             \x20 <body>\n\
+             \x20   <div>\n\
+             \x20     <p begin=\"00:00:01.000\" end=\"00:00:02.000\" tts:fontStyle=\"italic\">a &amp; b<br/>c</p>\n\
+             \x20   </div>\n\
+             \x20 </body>\n\
+             </tt>\n"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! WebVTT, a `WEBVTT` header then cues with optional identifiers and settings.
+
+use super::{
+    decode_entities, escape, format_timestamp, parse_timestamp, split_blocks, strip_tags, wrap_tags,
+};
+use super::{Cue, CueStyle, FormatError};
+
+pub(super) fn parse(input: &str) -> Result<Vec<Cue>, FormatError> {
+    let mut blocks = split_blocks(input).into_iter();
+    match blocks.next() {
+        Some((1, header)) if is_signature(header[0]) => {}
+        _ => return Err(FormatError::new(Some(1), "missing WEBVTT signature")),
+    }
+    blocks
+        .filter(|(_, lines)| {
+            !["NOTE", "STYLE", "REGION"]
+                .iter()
+                .any(|block| lines[0] == *block || lines[0].starts_with(&format!("{} ", block)))
+        })
+        .map(|(line, lines)| {
+            let timing = match lines.iter().position(|l| l.contains("-->")) {
+                Some(position) if position <= 1 => position,
+                _ => return Err(FormatError::new(Some(line), "missing cue timing")),
+            };
+            let mut split = lines[timing].splitn(2, "-->");
+            let start = split.next().and_then(parse_timestamp);
+            let mut rest = split.next().unwrap_or_default().split_whitespace();
+            let end = rest.next().and_then(parse_timestamp);
+            let settings = rest.collect::<Vec<_>>().join(" ");
+            let (start, end) = match (start, end) {
+                (Some(start), Some(end)) => (start, end),
+                _ => return Err(FormatError::new(Some(line + timing), "invalid cue timing")),
+            };
+            let mut style = CueStyle {
+                settings: Some(settings).filter(|settings| !settings.is_empty()),
+                ..Default::default()
+            };
+            let text = strip_tags(&lines[timing + 1..].join("\n"), &mut style);
+            Ok(Cue {
+                start,
+                end,
+                text: decode_entities(&text),
+                style,
+            })
+        })
+        .collect()
+}
+
+/// `WEBVTT` alone or followed by a space or a tab and a description.
+fn is_signature(line: &str) -> bool {
+    line == "WEBVTT" || line.starts_with("WEBVTT ") || line.starts_with("WEBVTT\t")
+}
+
+pub(super) fn emit(cues: &[Cue]) -> String {
+    let cues: String = cues
+        .iter()
+        .map(|cue| {
+            let mut text = wrap_tags(escape(&cue.text), &cue.style);
+            if let Some(color) = &cue.style.color {
+                // The `#` is kept so that hexadecimal colors are read back as such.
+                text = format!("<c.{}>{}</c>", color, text);
+            }
+            let settings = match &cue.style.settings {
+                Some(settings) => format!(" {}", settings),
+                None => String::new(),
+            };
+            format!(
+                "{} --> {}{}\n{}\n\n",
+                format_timestamp(cue.start, '.', 2),
+                format_timestamp(cue.end, '.', 2),
+                settings,
+                text
+            )
+        })
+        .collect();
+    format!("WEBVTT\n\n{}", cues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_vtt() {
+        let input = "WEBVTT - Conference talk\nKind: captions\n\n\
+                     NOTE written by hand\nover two lines\n\n\
+                     STYLE\n::cue { color: yellow }\n\n\
+                     intro\n00:01.000 --> 00:02.500 line:0 align:start\n\
+                     <v Ferris><c.yellow>Hello</c> &amp; <u>welcome</u>\n\n\
+                     01:00:00.000 --> 01:00:01.000\nBye\n";
+        let cues = parse(input).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start, Duration::from_millis(1000));
+        assert_eq!(cues[0].text, "Hello & welcome");
+        assert!(cues[0].style.underline);
+        assert_eq!(cues[0].style.color.as_deref(), Some("yellow"));
+        assert_eq!(
+            cues[0].style.settings.as_deref(),
+            Some("line:0 align:start")
+        );
+        assert_eq!(cues[1].start, Duration::from_secs(3600));
+        assert_eq!(
+            emit(&cues[..1]),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500 line:0 align:start\n\
+             <c.yellow><u>Hello &amp; welcome</u></c>\n\n"
+        );
+    }
+    #[test]
+    fn test_parse_vtt_requires_signature() {
+        let error = parse("00:01.000 --> 00:02.000\nHello").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: missing WEBVTT signature");
+        assert_eq!(parse("WEBVTTX\n").unwrap_err().line(), Some(1));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::caption::data::{Caption, CaptionListResponse};
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::query::encode;
+use crate::response::{build_bytes_response, build_empty_response, build_response};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use crate::upload::multipart::MultipartRelated;
+use crate::upload::source::DEFAULT_CONTENT_TYPE;
+
+pub mod data;
+pub mod format;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::caption::{data::*, format::*, query::*, CaptionEndPoint};
+}
+
+pub struct CaptionEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for CaptionEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        CaptionEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> CaptionEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("captions");
+        CaptionEndPoint { client, url }
+    }
+
+    /// Returns the caption tracks of a video, without their content.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/captions/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<CaptionListResponse, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Uploads a caption track, `track` is the content of the caption file.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/captions/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        caption: &Caption,
+        track: &[u8],
+    ) -> Result<Caption, ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.upload_url("captions", "multipart"),
+            query_search
+        );
+        let request = MultipartRelated::new()
+            .metadata(caption).map_err(invalid_body)?
+            .media(DEFAULT_CONTENT_TYPE, track)
+            .into_request(Method::Post, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Updates the draft status of a caption track, and replaces its content when `track` is
+    /// given. The caption must contain its id.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/captions/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        caption: &Caption,
+        track: Option<&[u8]>,
+    ) -> Result<Caption, ClientError> {
+        let request = match track {
+            Some(track) => {
+                let url = format!(
+                    "{}&{}",
+                    self.client.upload_url("captions", "multipart"),
+                    query_search
+                );
+                MultipartRelated::new()
+                    .metadata(caption).map_err(invalid_body)?
+                    .media(DEFAULT_CONTENT_TYPE, track)
+                    .into_request(Method::Put, url)
+            }
+            None => {
+                let url = format!("{}&{}", &self.url, query_search);
+                HttpRequest::new(Method::Put, url).json(caption).map_err(invalid_body)?
+            }
+        };
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Deletes a caption track.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/captions/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Downloads the content of a caption track, in its original format unless `tfmt` is given.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/captions/download .
+    pub async fn download(
+        &self,
+        caption_id: &str,
+        query_search: &str,
+    ) -> Result<Vec<u8>, ClientError> {
+        let mut url = self
+            .client
+            .endpoint_url(&format!("captions/{}", encode(caption_id)));
+        if !query_search.is_empty() {
+            url = format!("{}&{}", url, query_search);
+        }
+        let request = HttpRequest::new(Method::Get, url);
+        build_bytes_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caption::query::{CaptionFormat, DownloadCaption, InsertCaptions, ListCaptions};
+    use crate::testing::{json, MockTransport};
+    use crate::transport::HttpResponse;
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn caption(id: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#caption",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "videoId": "E6UTz_Doic8",
+                "lastUpdated": "2021-05-01T10:00:00Z",
+                "trackKind": "standard",
+                "language": "fr",
+                "name": "Français",
+                "audioTrackType": "unknown",
+                "isCC": false,
+                "isLarge": false,
+                "isEasyReader": false,
+                "isDraft": true,
+                "isAutoSynced": false,
+                "status": "serving"
+            }
+        })
+    }
+
+    #[test]
+    fn test_list_captions() {
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "captions",
+            &[("videoId", "E6UTz_Doic8")],
+            json(
+                200,
+                json!({
+                    "kind": "youtube#captionListResponse",
+                    "etag": "etag",
+                    "items": [caption("c1")]
+                })
+                .to_string(),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListCaptions::new("E6UTz_Doic8").build_query_parameters();
+        let captions = block_on(client.captions().list(query.get_query_params())).unwrap();
+        let snippet = captions.items[0].snippet.as_ref().unwrap();
+        assert_eq!(snippet.language, "fr");
+        assert_eq!(snippet.is_draft, Some(true));
+        assert_eq!(snippet.track_kind, Some(data::TrackKind::Standard));
+        assert_eq!(snippet.status, Some(data::CaptionStatus::Serving));
+    }
+    #[test]
+    fn test_insert_caption_sends_multipart_body() {
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "captions",
+            json(200, caption("c1").to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = InsertCaptions::default().build_query_parameters();
+        let metadata = Caption::new("E6UTz_Doic8", "fr", "Français").with_draft(true);
+        let track = b"1\n00:00:00,000 --> 00:00:01,000\nBonjour\n";
+        let caption = block_on(client.captions().insert(
+            query.get_query_params(),
+            &metadata,
+            track,
+        ))
+        .unwrap();
+        assert_eq!(caption.id, "c1");
+
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .starts_with("https://www.googleapis.com/upload/youtube/v3/captions?"));
+        assert!(request.url.contains("uploadType=multipart"));
+        assert_eq!(
+            request.get_header("content-type"),
+            Some("multipart/related; boundary=youtube_api_rs_multipart_boundary")
+        );
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+        assert!(body.contains(
+            r#"{"snippet":{"videoId":"E6UTz_Doic8","language":"fr","name":"Français","isDraft":true}}"#
+        ));
+        assert!(body.contains("Bonjour\n\r\n--youtube_api_rs_multipart_boundary--"));
+    }
+    #[test]
+    fn test_download_caption() {
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "captions/c1",
+            &[("tfmt", "srt")],
+            HttpResponse::new(200, b"1\n00:00:00,000 --> 00:00:01,000\nBonjour\n".to_vec()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = DownloadCaption::default()
+            .parse_tfmt(CaptionFormat::Srt)
+            .build_query_parameters();
+        let track = block_on(client.captions().download("c1", query.get_query_params())).unwrap();
+        assert!(track.ends_with(b"Bonjour\n"));
+    }
+}
@@ -0,0 +1,353 @@
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the caption resource, see https://developers.google.com/youtube/v3/docs/captions#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptionPart {
+    Id,
+    Snippet,
+}
+
+impl Part for CaptionPart {
+    const ALL: &'static [Self] = &[CaptionPart::Id, CaptionPart::Snippet];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            CaptionPart::Id => "id",
+            CaptionPart::Snippet => "snippet",
+        }
+    }
+}
+
+/// Format a caption track can be downloaded in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptionFormat {
+    /// SubViewer subtitle
+    Sbv,
+    /// Scenarist Closed Caption
+    Scc,
+    /// SubRip subtitle
+    Srt,
+    /// Timed Text Markup Language
+    Ttml,
+    /// Web Video Text Tracks
+    Vtt,
+}
+
+impl CaptionFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CaptionFormat::Sbv => "sbv",
+            CaptionFormat::Scc => "scc",
+            CaptionFormat::Srt => "srt",
+            CaptionFormat::Ttml => "ttml",
+            CaptionFormat::Vtt => "vtt",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListCaptions {
+    /// Caption resource properties that the API response will include
+    part: PartSet<CaptionPart>,
+    /// Video whose caption tracks are listed
+    video_id: String,
+    /// Only returns the tracks with these ids
+    id: Vec<String>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListCaptions {
+    /// Create to list the caption tracks of a video
+    pub fn new(video_id: &str) -> ListCaptions {
+        ListCaptions {
+            video_id: video_id.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CaptionPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse ids to the id field
+    pub fn parse_ids(mut self, ids: &[&str]) -> Self {
+        self.id = ids.iter().map(|id| id.to_string()).collect();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<CaptionPart> {
+        &self.part
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let id = Some(self.id.join(",")).filter(|id| !id.is_empty());
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push("videoId", &self.video_id)
+            .push_opt("id", id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertCaptions {
+    /// Caption resource properties that are set and returned
+    part: PartSet<CaptionPart>,
+    /// Synchronize a transcript without timings with the audio of the video
+    sync: bool,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertCaptions {
+    fn default() -> Self {
+        InsertCaptions {
+            part: PartSet::new().with(CaptionPart::Snippet),
+            sync: false,
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertCaptions {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CaptionPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse sync to its field
+    pub fn parse_sync(mut self, sync: bool) -> Self {
+        self.sync = sync;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt("sync", Some(true).filter(|_| self.sync))
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpdateCaptions {
+    /// Caption resource properties that are updated and returned
+    part: PartSet<CaptionPart>,
+    /// Synchronize a transcript without timings with the audio of the video
+    sync: bool,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for UpdateCaptions {
+    fn default() -> Self {
+        UpdateCaptions {
+            part: PartSet::new().with(CaptionPart::Snippet),
+            sync: false,
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl UpdateCaptions {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CaptionPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse sync to its field
+    pub fn parse_sync(mut self, sync: bool) -> Self {
+        self.sync = sync;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt("sync", Some(true).filter(|_| self.sync))
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DeleteCaption {
+    /// ID of the caption track to be deleted
+    id: String,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DeleteCaption {
+    pub fn new(id: &str) -> DeleteCaption {
+        DeleteCaption::default().parse_id(id)
+    }
+    /// Parse id to its field
+    pub fn parse_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("id", &self.id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+/// The id of the downloaded track is part of the path, see `CaptionEndPoint::download`.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DownloadCaption {
+    /// Format of the downloaded track, the original one when missing
+    tfmt: Option<CaptionFormat>,
+    /// ISO 639-1 language the track is translated to
+    tlang: Option<String>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DownloadCaption {
+    /// Parse tfmt to its field
+    pub fn parse_tfmt(mut self, tfmt: CaptionFormat) -> Self {
+        self.tfmt = Some(tfmt);
+        self
+    }
+    /// Parse tlang to its field
+    pub fn parse_tlang(mut self, tlang: &str) -> Self {
+        self.tlang = Some(tlang.to_string());
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push_opt("tfmt", self.tfmt.as_ref().map(CaptionFormat::as_str))
+            .push_opt("tlang", self.tlang.as_ref())
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_captions() {
+        let captions = ListCaptions::new("E6UTz_Doic8")
+            .parse_ids(&["c1", "c2"])
+            .build_query_parameters();
+        assert_eq!(
+            captions.get_query_params(),
+            "part=id,snippet&videoId=E6UTz_Doic8&id=c1,c2"
+        )
+    }
+    #[test]
+    fn test_build_query_params_write_captions() {
+        let insert = InsertCaptions::default()
+            .parse_sync(true)
+            .build_query_parameters();
+        assert_eq!(insert.get_query_params(), "part=snippet&sync=true");
+        let update = UpdateCaptions::default().build_query_parameters();
+        assert_eq!(update.get_query_params(), "part=snippet");
+        let delete = DeleteCaption::new("c1").build_query_parameters();
+        assert_eq!(delete.get_query_params(), "id=c1");
+    }
+    #[test]
+    fn test_build_query_params_download_caption() {
+        let download = DownloadCaption::default()
+            .parse_tfmt(CaptionFormat::Vtt)
+            .parse_tlang("fr")
+            .build_query_parameters();
+        assert_eq!(download.get_query_params(), "tfmt=vtt&tlang=fr");
+        let download = DownloadCaption::default().build_query_parameters();
+        assert_eq!(download.get_query_params(), "");
+    }
+}
@@ -0,0 +1,207 @@
+use crate::resource::{option_u64, Localization, PrivacyStatus, Thumbnails};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The channel data model https://developers.google.com/youtube/v3/docs/channels#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct YoutubeChannel {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<ChannelSnippet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_details: Option<ChannelContentDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<ChannelStatistics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding_settings: Option<ChannelBrandingSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChannelStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_details: Option<ChannelTopicDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_details: Option<ChannelAuditDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_owner_details: Option<ChannelContentOwnerDetails>,
+    /// Translations of the title and description, keyed by BCP-47 language code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localizations: Option<BTreeMap<String, Localization>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSnippet {
+    pub title: String,
+    pub description: String,
+    /// Custom URL, the handle prefixed with `@` for most channels.
+    pub custom_url: Option<String>,
+    pub published_at: String,
+    pub thumbnails: Option<Thumbnails>,
+    pub default_language: Option<String>,
+    pub localized: Option<Localization>,
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelContentDetails {
+    pub related_playlists: RelatedPlaylists,
+}
+
+/// Playlists automatically managed by Youtube for the channel.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedPlaylists {
+    /// Videos liked by the channel, only returned to the owner
+    pub likes: Option<String>,
+    /// Videos uploaded by the channel
+    pub uploads: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatistics {
+    #[serde(default, with = "option_u64")]
+    pub view_count: Option<u64>,
+    /// Rounded down to three significant figures.
+    #[serde(default, with = "option_u64")]
+    pub subscriber_count: Option<u64>,
+    pub hidden_subscriber_count: bool,
+    #[serde(default, with = "option_u64")]
+    pub video_count: Option<u64>,
+}
+
+/// Branding properties https://developers.google.com/youtube/v3/docs/channels#brandingSettings
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelBrandingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageSettings>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Space-separated keywords, quoted when they contain spaces.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_analytics_account_id: Option<String>,
+    /// Video played in the featured video module for unsubscribed viewers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unsubscribed_trailer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_external_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatus {
+    pub privacy_status: Option<PrivacyStatus>,
+    pub is_linked: Option<bool>,
+    /// `allowed`, `disallowed`, `eligible` or `longUploadsUnspecified`.
+    pub long_uploads_status: Option<String>,
+    pub made_for_kids: Option<bool>,
+    pub self_declared_made_for_kids: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelTopicDetails {
+    pub topic_ids: Option<Vec<String>>,
+    /// Wikipedia URLs describing the content of the channel.
+    pub topic_categories: Option<Vec<String>>,
+}
+
+/// Standing of the channel, only returned to the owner.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelAuditDetails {
+    pub overall_good_standing: bool,
+    pub community_guidelines_good_standing: bool,
+    pub copyright_strikes_good_standing: bool,
+    pub content_id_claims_good_standing: bool,
+}
+
+/// Content owner linked to the channel, only visible to YouTube partners.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelContentOwnerDetails {
+    pub content_owner: Option<String>,
+    pub time_linked: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_channel() {
+        let channel = json!({
+            "kind": "youtube#channel",
+            "etag": "etag",
+            "id": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+            "snippet": {
+                "title": "Google for Developers",
+                "description": "Subscribe",
+                "customUrl": "@googledevelopers",
+                "publishedAt": "2007-08-23T00:34:43Z",
+                "thumbnails": {"default": {"url": "https://yt3.ggpht.com/a", "width": 88, "height": 88}},
+                "country": "US"
+            },
+            "contentDetails": {"relatedPlaylists": {"likes": "", "uploads": "UU_x5XG1OV2P6uZZ5FSM9Ttw"}},
+            "statistics": {"viewCount": "250464616", "subscriberCount": "2470000", "hiddenSubscriberCount": false, "videoCount": "6227"},
+            "brandingSettings": {"channel": {"title": "Google for Developers", "keywords": "\"Google Developers\" Android"}},
+            "status": {"privacyStatus": "public", "isLinked": true, "longUploadsStatus": "longUploadsUnspecified"},
+            "topicDetails": {"topicCategories": ["https://en.wikipedia.org/wiki/Technology"]}
+        });
+        let channel: YoutubeChannel = serde_json::from_value(channel).unwrap();
+        assert_eq!(
+            channel.snippet.unwrap().custom_url.as_deref(),
+            Some("@googledevelopers")
+        );
+        assert_eq!(
+            channel
+                .content_details
+                .unwrap()
+                .related_playlists
+                .uploads
+                .as_deref(),
+            Some("UU_x5XG1OV2P6uZZ5FSM9Ttw")
+        );
+        assert_eq!(
+            channel.statistics.unwrap().subscriber_count,
+            Some(2_470_000)
+        );
+        assert_eq!(
+            channel.status.unwrap().privacy_status,
+            Some(PrivacyStatus::Public)
+        );
+    }
+    #[test]
+    fn test_partial_channel_round_trip() {
+        let channel: YoutubeChannel = serde_json::from_value(json!({"id": "UC1"})).unwrap();
+        assert_eq!(serde_json::to_value(&channel).unwrap(), json!({"id": "UC1"}));
+    }
+}
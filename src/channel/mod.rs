@@ -0,0 +1,161 @@
+use crate::channel::data::YoutubeChannel;
+use crate::channel::query::ListChannels;
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::response::{build_response, paginate_items, paginate_pages, YoutubeListResponse};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::channel::{data::*, query::*, ChannelEndPoint};
+}
+
+pub struct ChannelEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for ChannelEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        ChannelEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> ChannelEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("channels");
+        ChannelEndPoint { client, url }
+    }
+
+    /// Returns a collection of zero or more channel resources that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/channels/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<YoutubeChannel>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the channels of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` channels were returned.
+    pub fn paginate(
+        &self,
+        query: ListChannels,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<YoutubeChannel, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListChannels,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<YoutubeChannel>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Updates the brandingSettings or localizations of a channel, the channel must contain its
+    /// id and every property of the updated parts since the missing ones are deleted.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/channels/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        channel: &YoutubeChannel,
+    ) -> Result<YoutubeChannel, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Put, url).json(channel).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel::data::{ChannelBrandingSettings, ChannelSettings};
+    use crate::channel::query::UpdateChannels;
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn channel(id: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#channel",
+            "etag": "etag",
+            "id": id,
+            "contentDetails": {"relatedPlaylists": {"uploads": format!("UU{}", id)}}
+        })
+    }
+
+    #[test]
+    fn test_list_channels() {
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "channels",
+            &[("forHandle", "@rustlang")],
+            json(
+                200,
+                fixtures::list("youtube#channelListResponse", vec![channel("123")], None),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListChannels::create_with_handle("@rustlang").build_query_parameters();
+        let channels = block_on(client.channels().list(query.get_query_params())).unwrap();
+        let uploads = &channels.items[0]
+            .content_details
+            .as_ref()
+            .unwrap()
+            .related_playlists
+            .uploads;
+        assert_eq!(uploads.as_deref(), Some("UU123"));
+    }
+    #[test]
+    fn test_update_channel_sends_json_body() {
+        let transport = MockTransport::new().respond(
+            Method::Put,
+            "channels",
+            json(200, channel("123").to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = UpdateChannels::default().build_query_parameters();
+        let update = YoutubeChannel {
+            id: "123".to_string(),
+            branding_settings: Some(ChannelBrandingSettings {
+                channel: Some(ChannelSettings {
+                    description: Some("New description".to_string()),
+                    ..Default::default()
+                }),
+                image: None,
+            }),
+            ..Default::default()
+        };
+        let channel =
+            block_on(client.channels().update(query.get_query_params(), &update)).unwrap();
+        assert_eq!(channel.id, "123");
+
+        let request = client.transport().last_request().unwrap();
+        assert_eq!(request.get_header("content-type"), Some("application/json"));
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            body["brandingSettings"],
+            json!({"channel": {"description": "New description"}})
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use crate::client::ClientError;
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the channel resource, see https://developers.google.com/youtube/v3/docs/channels#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChannelPart {
+    Snippet,
+    ContentDetails,
+    Statistics,
+    BrandingSettings,
+    Status,
+    TopicDetails,
+    Id,
+    Localizations,
+    AuditDetails,
+    ContentOwnerDetails,
+}
+
+impl Part for ChannelPart {
+    const ALL: &'static [Self] = &[
+        ChannelPart::Snippet,
+        ChannelPart::ContentDetails,
+        ChannelPart::Statistics,
+        ChannelPart::BrandingSettings,
+        ChannelPart::Status,
+        ChannelPart::TopicDetails,
+        ChannelPart::Id,
+        ChannelPart::Localizations,
+        ChannelPart::AuditDetails,
+        ChannelPart::ContentOwnerDetails,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChannelPart::Snippet => "snippet",
+            ChannelPart::ContentDetails => "contentDetails",
+            ChannelPart::Statistics => "statistics",
+            ChannelPart::BrandingSettings => "brandingSettings",
+            ChannelPart::Status => "status",
+            ChannelPart::TopicDetails => "topicDetails",
+            ChannelPart::Id => "id",
+            ChannelPart::Localizations => "localizations",
+            ChannelPart::AuditDetails => "auditDetails",
+            ChannelPart::ContentOwnerDetails => "contentOwnerDetails",
+        }
+    }
+
+    fn requires_owner(&self) -> bool {
+        matches!(
+            self,
+            ChannelPart::AuditDetails | ChannelPart::ContentOwnerDetails
+        )
+    }
+}
+
+/// Filter selecting the listed channels, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+pub enum ChannelFilter {
+    /// Channels with the given ids
+    Id(Vec<String>),
+    /// Channel of a legacy username
+    ForUsername(String),
+    /// Channel of a handle, with or without the leading `@`
+    ForHandle(String),
+    /// Channel of the authenticated user
+    #[default]
+    Mine,
+    /// Channels managed by the content owner given with `onBehalfOfContentOwner`
+    ManagedByMe,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListChannels {
+    /// Channel resource properties that the API response will include
+    part: PartSet<ChannelPart>,
+    /// Selects the channels to list
+    filter: ChannelFilter,
+    /// Language used for the localized metadata
+    hl: Option<String>,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListChannels {
+    /// Create with the given filter, filtering by ids requires at least one
+    pub fn new(filter: ChannelFilter) -> Result<ListChannels, ClientError> {
+        ListChannels::default().parse_filter(filter)
+    }
+    fn with_filter(filter: ChannelFilter) -> ListChannels {
+        ListChannels {
+            filter,
+            ..ListChannels::default()
+        }
+    }
+    /// Create to list the channel of the authenticated user
+    pub fn create_with_mine() -> ListChannels {
+        ListChannels::with_filter(ChannelFilter::Mine)
+    }
+    /// Create to list the channels with the given ids, at least one is required
+    pub fn create_with_ids(ids: &[&str]) -> Result<ListChannels, ClientError> {
+        ListChannels::new(ChannelFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Create to list the channel of a legacy username
+    pub fn create_with_username(username: &str) -> ListChannels {
+        ListChannels::with_filter(ChannelFilter::ForUsername(username.to_string()))
+    }
+    /// Create to list the channel of a handle such as `@GoogleDevelopers`
+    pub fn create_with_handle(handle: &str) -> ListChannels {
+        ListChannels::with_filter(ChannelFilter::ForHandle(handle.to_string()))
+    }
+    /// Create to list the channels managed by a content owner
+    pub fn create_with_managed_by_me(content_owner: &str) -> ListChannels {
+        ListChannels::with_filter(ChannelFilter::ManagedByMe)
+            .parse_on_behalf_of_content_owner(content_owner)
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<ChannelPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: ChannelFilter) -> Result<Self, ClientError> {
+        if filter == ChannelFilter::Id(Vec::new()) {
+            return Err(ClientError::InvalidRequest(
+                "at least one channel id is required".to_string(),
+            ));
+        }
+        self.filter = filter;
+        Ok(self)
+    }
+    /// Parse hl to its field
+    pub fn parse_hl(mut self, hl: &str) -> Self {
+        self.hl = Some(hl.to_string());
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<ChannelPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &ChannelFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            ChannelFilter::Id(ids) => query.push("id", ids.join(",")),
+            ChannelFilter::ForUsername(username) => query.push("forUsername", username),
+            ChannelFilter::ForHandle(handle) => query.push("forHandle", handle),
+            ChannelFilter::Mine => query.push("mine", true),
+            ChannelFilter::ManagedByMe => query.push("managedByMe", true),
+        };
+        self.query_params = query
+            .push_opt("hl", self.hl.as_ref())
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpdateChannels {
+    /// Channel resource properties that are updated, only brandingSettings and localizations
+    /// can be written
+    part: PartSet<ChannelPart>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for UpdateChannels {
+    fn default() -> Self {
+        UpdateChannels {
+            part: PartSet::new()
+                .with(ChannelPart::BrandingSettings)
+                .with(ChannelPart::Localizations),
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl UpdateChannels {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<ChannelPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_mine() {
+        let channels = ListChannels::create_with_mine().build_query_parameters();
+        assert_eq!(channels.get_query_params(), "part=snippet,contentDetails,statistics,brandingSettings,status,topicDetails,id,localizations&mine=true")
+    }
+    #[test]
+    fn test_build_query_params_list_by_handle() {
+        let channels = ListChannels::create_with_handle("@GoogleDevelopers")
+            .parse_part(PartSet::new().with(ChannelPart::Snippet))
+            .parse_hl("fr")
+            .build_query_parameters();
+        assert_eq!(
+            channels.get_query_params(),
+            "part=snippet&forHandle=%40GoogleDevelopers&hl=fr"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_by_ids() {
+        let channels = ListChannels::create_with_ids(&["UC1", "UC2"])
+            .unwrap()
+            .parse_part(PartSet::new().with(ChannelPart::Statistics))
+            .parse_max_results(2)
+            .build_query_parameters();
+        assert_eq!(
+            channels.get_query_params(),
+            "part=statistics&id=UC1,UC2&maxResults=2"
+        );
+        assert!(ListChannels::create_with_ids(&[]).is_err());
+        assert!(ListChannels::new(ChannelFilter::Id(Vec::new())).is_err());
+    }
+    #[test]
+    fn test_build_query_params_list_managed_by_me() {
+        let channels = ListChannels::create_with_managed_by_me("owner")
+            .parse_part(PartSet::new().with(ChannelPart::ContentOwnerDetails))
+            .build_query_parameters();
+        assert!(channels.get_part().requires_owner());
+        assert_eq!(
+            channels.get_query_params(),
+            "part=contentOwnerDetails&managedByMe=true&onBehalfOfContentOwner=owner"
+        )
+    }
+    #[test]
+    fn test_build_query_params_update_channel() {
+        let update = UpdateChannels::default().build_query_parameters();
+        assert_eq!(
+            update.get_query_params(),
+            "part=brandingSettings,localizations"
+        )
+    }
+}
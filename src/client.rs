@@ -1,56 +1,147 @@
+use crate::activity::ActivityEndPoint;
+use crate::caption::CaptionEndPoint;
+use crate::channel::ChannelEndPoint;
+use crate::comment::{CommentEndPoint, CommentThreadEndPoint};
+use crate::playlist::PlaylistEndPoint;
+use crate::playlist_item::PlaylistItemEndPoint;
+use crate::search::SearchEndPoint;
+use crate::subscription::SubscriptionEndPoint;
+use crate::thumbnail::ThumbnailEndPoint;
 use crate::video::VideoEndPoint;
-use crate::error::YoutubeError;
-use seed::fetch::FetchError;
+use crate::watermark::WatermarkEndPoint;
+use crate::query::QueryString;
+#[cfg(any(feature = "seed-fetch", feature = "native"))]
+use crate::transport::DefaultTransport;
+use crate::transport::retry::{RetryPolicy, RetryTransport, Timer};
+use crate::transport::HttpTransport;
+
+pub use crate::error::ClientError;
 
 /// Api object that contains basic info for querying.
-pub struct Client {
+pub struct Client<T> {
     base_url: String,
+    upload_url: String,
     token: String,
     api_key: String,
+    transport: T,
 }
 
-impl Client {
+#[cfg(any(feature = "seed-fetch", feature = "native"))]
+impl Client<DefaultTransport> {
+    /// Create a client using the default transport of the enabled features.
     pub fn new(token: &str, api_key: &str) -> Self {
+        Client::with_transport(token, api_key, DefaultTransport::default())
+    }
+}
+
+impl<T: HttpTransport> Client<T> {
+    /// Create a client sending its requests through the given transport.
+    pub fn with_transport(token: &str, api_key: &str, transport: T) -> Self {
         Client {
             base_url: "https://www.googleapis.com/youtube/v3".to_string(),
-            api_key:api_key.to_string(),
+            upload_url: "https://www.googleapis.com/upload/youtube/v3".to_string(),
+            api_key: api_key.to_string(),
             token: token.to_string(),
+            transport,
+        }
+    }
+
+    /// Retry the requests failing for a transient reason according to the policy, the timer
+    /// waits between the attempts.
+    pub fn with_retry<S: Timer>(
+        self,
+        policy: RetryPolicy,
+        timer: S,
+    ) -> Client<RetryTransport<T, S>> {
+        Client {
+            base_url: self.base_url,
+            upload_url: self.upload_url,
+            token: self.token,
+            api_key: self.api_key,
+            transport: RetryTransport::new(self.transport, policy, timer),
         }
     }
 
     /// Get the video Api.
-    pub fn video(&mut self) -> VideoEndPoint {
-        VideoEndPoint::new(
-            format!(
-                "{}/API?access_token={}&api={}",
-                self.base_url, self.token, self.api_key
-            )
-                ,
-        )
+    pub fn video(&self) -> VideoEndPoint<'_, T> {
+        VideoEndPoint::new(self)
     }
-}
 
-#[derive(Debug)]
-pub enum ClientError {
-    Youtube(YoutubeError),
-    Client(FetchError),
-}
+    /// Get the activity Api.
+    pub fn activities(&self) -> ActivityEndPoint<'_, T> {
+        ActivityEndPoint::new(self)
+    }
 
-impl From<YoutubeError> for ClientError {
-    fn from(e: YoutubeError) -> Self {
-        ClientError::Youtube(e)
+    /// Get the caption Api.
+    pub fn captions(&self) -> CaptionEndPoint<'_, T> {
+        CaptionEndPoint::new(self)
     }
-}
 
-impl From<serde_json::Error> for ClientError {
-    fn from(e: serde_json::Error) -> Self {
-        ClientError::Client(FetchError::SerdeError(e))
+    /// Get the channel Api.
+    pub fn channels(&self) -> ChannelEndPoint<'_, T> {
+        ChannelEndPoint::new(self)
+    }
+
+    /// Get the comment thread Api.
+    pub fn comment_threads(&self) -> CommentThreadEndPoint<'_, T> {
+        CommentThreadEndPoint::new(self)
+    }
+
+    /// Get the comment Api.
+    pub fn comments(&self) -> CommentEndPoint<'_, T> {
+        CommentEndPoint::new(self)
+    }
+
+    /// Get the playlist Api.
+    pub fn playlists(&self) -> PlaylistEndPoint<'_, T> {
+        PlaylistEndPoint::new(self)
+    }
+
+    /// Get the playlist item Api.
+    pub fn playlist_items(&self) -> PlaylistItemEndPoint<'_, T> {
+        PlaylistItemEndPoint::new(self)
+    }
+
+    /// Get the search Api.
+    pub fn search(&self) -> SearchEndPoint<'_, T> {
+        SearchEndPoint::new(self)
+    }
+
+    /// Get the subscription Api.
+    pub fn subscriptions(&self) -> SubscriptionEndPoint<'_, T> {
+        SubscriptionEndPoint::new(self)
+    }
+
+    /// Get the thumbnail Api.
+    pub fn thumbnails(&self) -> ThumbnailEndPoint<'_, T> {
+        ThumbnailEndPoint::new(self)
+    }
+
+    /// Get the watermark Api.
+    pub fn watermarks(&self) -> WatermarkEndPoint<'_, T> {
+        WatermarkEndPoint::new(self)
+    }
+
+    /// Get the transport used to send the requests.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Build the url of a resource with the credentials.
+    pub(crate) fn endpoint_url(&self, resource: &str) -> String {
+        let credentials = QueryString::new()
+            .push("access_token", &self.token)
+            .push("api", &self.api_key);
+        format!("{}/{}?{}", self.base_url, resource, credentials)
     }
-}
 
-impl From<FetchError> for ClientError {
-    fn from(e: FetchError) -> Self {
-        ClientError::Client(e)
+    /// Build the url uploading the media of a resource with the credentials.
+    pub(crate) fn upload_url(&self, resource: &str, upload_type: &str) -> String {
+        let credentials = QueryString::new()
+            .push("access_token", &self.token)
+            .push("api", &self.api_key)
+            .push("uploadType", upload_type);
+        format!("{}/{}?{}", self.upload_url, resource, credentials)
     }
 }
 
@@ -58,6 +149,7 @@ impl From<FetchError> for ClientError {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::testing::MockTransport;
     #[test]
     fn check_config_loaded() {
         let config = Config {
@@ -65,7 +157,7 @@ mod tests {
             client_id: "myClientID".to_string(),
             redirect_uri: "myRedirectURI".to_string(),
         };
-        let client = Client::new("123", &config.api_key);
+        let client = Client::with_transport("123", &config.api_key, MockTransport::new());
         assert_eq!(client.api_key, "ADF32723289FWY");
         assert_eq!(client.token, "123");
 
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+/// The comment thread data model https://developers.google.com/youtube/v3/docs/commentThreads#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThread {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting a thread
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<CommentThreadSnippet>,
+    #[serde(skip_serializing)]
+    pub replies: Option<CommentThreadReplies>,
+}
+
+impl CommentThread {
+    /// Create the body of a new top-level comment on a video.
+    pub fn new(channel_id: &str, video_id: &str, text: &str) -> Self {
+        CommentThread {
+            snippet: Some(CommentThreadSnippet {
+                channel_id: channel_id.to_string(),
+                video_id: Some(video_id.to_string()),
+                top_level_comment: Comment {
+                    snippet: Some(CommentSnippet {
+                        text_original: text.to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The read-only properties are not sent back when inserting.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadSnippet {
+    pub channel_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    pub top_level_comment: Comment,
+    #[serde(default, skip_serializing)]
+    pub can_reply: bool,
+    #[serde(default, skip_serializing)]
+    pub total_reply_count: u32,
+    #[serde(default, skip_serializing)]
+    pub is_public: bool,
+}
+
+/// Part of the replies of a thread, use `comments.list` to retrieve all of them.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadReplies {
+    pub comments: Vec<Comment>,
+}
+
+/// The comment data model https://developers.google.com/youtube/v3/docs/comments#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting a comment
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<CommentSnippet>,
+}
+
+impl Comment {
+    /// Create the body of a reply to the top-level comment `parent_id`.
+    pub fn reply(parent_id: &str, text: &str) -> Self {
+        Comment {
+            snippet: Some(CommentSnippet {
+                parent_id: Some(parent_id.to_string()),
+                text_original: text.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+    /// Create the body replacing the text of the comment `id`.
+    pub fn edit(id: &str, text: &str) -> Self {
+        Comment {
+            id: id.to_string(),
+            snippet: Some(CommentSnippet {
+                text_original: text.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The read-only properties are not sent back when inserting or updating.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentSnippet {
+    #[serde(default, skip_serializing)]
+    pub author_display_name: String,
+    #[serde(skip_serializing)]
+    pub author_profile_image_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub author_channel_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub author_channel_id: Option<AuthorChannelId>,
+    #[serde(skip_serializing)]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing)]
+    pub video_id: Option<String>,
+    /// Text in the requested `textFormat`
+    #[serde(default, skip_serializing)]
+    pub text_display: String,
+    /// Text as written by the author, only returned to the author
+    #[serde(default)]
+    pub text_original: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub can_rate: bool,
+    /// `like` or `none`
+    #[serde(skip_serializing)]
+    pub viewer_rating: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub like_count: u32,
+    #[serde(skip_serializing)]
+    pub moderation_status: Option<ModerationStatus>,
+    #[serde(skip_serializing)]
+    pub published_at: Option<String>,
+    #[serde(skip_serializing)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct AuthorChannelId {
+    pub value: String,
+}
+
+/// Moderation status of a comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModerationStatus {
+    HeldForReview,
+    LikelySpam,
+    Published,
+    Rejected,
+}
+
+impl ModerationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationStatus::HeldForReview => "heldForReview",
+            ModerationStatus::LikelySpam => "likelySpam",
+            ModerationStatus::Published => "published",
+            ModerationStatus::Rejected => "rejected",
+        }
+    }
+}
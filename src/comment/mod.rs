@@ -0,0 +1,360 @@
+use crate::client::{Client, ClientError};
+use crate::comment::data::{Comment, CommentThread};
+use crate::comment::query::{ListCommentThreads, ListComments};
+use crate::error::invalid_body;
+use crate::response::{
+    build_empty_response, build_response, paginate_items, paginate_pages, YoutubeListResponse,
+};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::comment::{data::*, query::*, CommentEndPoint, CommentThreadEndPoint};
+}
+
+pub struct CommentThreadEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for CommentThreadEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        CommentThreadEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> CommentThreadEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("commentThreads");
+        CommentThreadEndPoint { client, url }
+    }
+
+    /// Returns the comment threads that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/commentThreads/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<CommentThread>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the threads of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` threads were returned.
+    pub fn paginate(
+        &self,
+        query: ListCommentThreads,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<CommentThread, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListCommentThreads,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<CommentThread>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Creates a top-level comment, use `comments.insert` to reply to it.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/commentThreads/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        thread: &CommentThread,
+    ) -> Result<CommentThread, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url).json(thread).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+}
+
+pub struct CommentEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for CommentEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        CommentEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> CommentEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("comments");
+        CommentEndPoint { client, url }
+    }
+
+    /// Returns the comments that match the request criteria, usually the replies to a comment.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/comments/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<Comment>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the comments of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` comments were returned.
+    pub fn paginate(
+        &self,
+        query: ListComments,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<Comment, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListComments,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<Comment>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Replies to a top-level comment.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/comments/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        comment: &Comment,
+    ) -> Result<Comment, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url).json(comment).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Modifies the text of a comment.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/comments/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        comment: &Comment,
+    ) -> Result<Comment, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Put, url).json(comment).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Deletes a comment.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/comments/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Sets the moderation status of comments, needs the authorization of the channel owner.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/comments/setModerationStatus .
+    pub async fn set_moderation_status(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.endpoint_url("comments/setModerationStatus"),
+            query_search
+        );
+        let request = HttpRequest::new(Method::Post, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comment::data::ModerationStatus;
+    use crate::comment::query::{InsertCommentThreads, InsertComments, SetModerationStatus};
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use futures::StreamExt;
+    use serde_json::json;
+
+    fn comment(id: &str, text: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#comment",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "authorDisplayName": "Ferris",
+                "videoId": "E6UTz_Doic8",
+                "textDisplay": text,
+                "textOriginal": text,
+                "canRate": true,
+                "viewerRating": "none",
+                "likeCount": 2,
+                "moderationStatus": "heldForReview",
+                "publishedAt": "2021-05-01T10:00:00Z",
+                "updatedAt": "2021-05-01T10:00:00Z"
+            }
+        })
+    }
+
+    fn thread(id: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#commentThread",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "channelId": "UC123",
+                "videoId": "E6UTz_Doic8",
+                "topLevelComment": comment(id, "First"),
+                "canReply": true,
+                "totalReplyCount": 1,
+                "isPublic": true
+            },
+            "replies": {"comments": [comment("r1", "Reply")]}
+        })
+    }
+
+    #[test]
+    fn test_paginate_comment_threads() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "commentThreads",
+                json(
+                    200,
+                    fixtures::list(
+                        "youtube#commentThreadListResponse",
+                        vec![thread("t1")],
+                        Some("page2"),
+                    ),
+                ),
+            )
+            .respond_when(
+                Method::Get,
+                "commentThreads",
+                &[("pageToken", "page2")],
+                json(
+                    200,
+                    fixtures::list(
+                        "youtube#commentThreadListResponse",
+                        vec![thread("t2")],
+                        None,
+                    ),
+                ),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListCommentThreads::create_with_video_id("E6UTz_Doic8");
+        let threads = block_on(
+            client
+                .comment_threads()
+                .paginate(query, None)
+                .collect::<Vec<_>>(),
+        );
+        let threads: Vec<_> = threads.into_iter().map(Result::unwrap).collect();
+        assert_eq!(threads.len(), 2);
+        let snippet = threads[1].snippet.as_ref().unwrap();
+        assert_eq!(snippet.total_reply_count, 1);
+        assert_eq!(
+            snippet
+                .top_level_comment
+                .snippet
+                .as_ref()
+                .unwrap()
+                .moderation_status,
+            Some(ModerationStatus::HeldForReview)
+        );
+        assert_eq!(threads[1].replies.as_ref().unwrap().comments[0].id, "r1");
+    }
+    #[test]
+    fn test_insert_comment_thread_and_reply() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Post,
+                "commentThreads",
+                json(200, thread("t1").to_string()),
+            )
+            .respond(
+                Method::Post,
+                "comments",
+                json(200, comment("r1", "Thanks").to_string()),
+            );
+        let client = Client::with_transport("token", "key", transport);
+
+        let query = InsertCommentThreads::default().build_query_parameters();
+        let body = CommentThread::new("UC123", "E6UTz_Doic8", "First");
+        let thread = block_on(
+            client
+                .comment_threads()
+                .insert(query.get_query_params(), &body),
+        )
+        .unwrap();
+        let request = client.transport().last_request().unwrap();
+        let sent: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            sent,
+            json!({"snippet": {
+                "channelId": "UC123",
+                "videoId": "E6UTz_Doic8",
+                "topLevelComment": {"snippet": {"textOriginal": "First"}}
+            }})
+        );
+
+        let query = InsertComments::default().build_query_parameters();
+        let body = Comment::reply(&thread.id, "Thanks");
+        let reply = block_on(client.comments().insert(query.get_query_params(), &body)).unwrap();
+        assert_eq!(reply.id, "r1");
+        let request = client.transport().last_request().unwrap();
+        let sent: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            sent,
+            json!({"snippet": {"textOriginal": "Thanks", "parentId": "t1"}})
+        );
+    }
+    #[test]
+    fn test_set_moderation_status() {
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "comments/setModerationStatus",
+            json(204, ""),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = SetModerationStatus::new(&["c1"], ModerationStatus::Published)
+            .unwrap()
+            .build_query_parameters();
+        block_on(
+            client
+                .comments()
+                .set_moderation_status(query.get_query_params()),
+        )
+        .unwrap();
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .contains("/comments/setModerationStatus?access_token=token"));
+        assert!(request.url.ends_with("&id=c1&moderationStatus=published"));
+    }
+}
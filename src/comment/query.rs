@@ -0,0 +1,638 @@
+use crate::client::ClientError;
+use crate::comment::data::ModerationStatus;
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the comment thread resource, see https://developers.google.com/youtube/v3/docs/commentThreads#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentThreadPart {
+    Id,
+    Replies,
+    Snippet,
+}
+
+impl Part for CommentThreadPart {
+    const ALL: &'static [Self] = &[
+        CommentThreadPart::Id,
+        CommentThreadPart::Replies,
+        CommentThreadPart::Snippet,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            CommentThreadPart::Id => "id",
+            CommentThreadPart::Replies => "replies",
+            CommentThreadPart::Snippet => "snippet",
+        }
+    }
+}
+
+/// The parts of the comment resource, see https://developers.google.com/youtube/v3/docs/comments#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentPart {
+    Id,
+    Snippet,
+}
+
+impl Part for CommentPart {
+    const ALL: &'static [Self] = &[CommentPart::Id, CommentPart::Snippet];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            CommentPart::Id => "id",
+            CommentPart::Snippet => "snippet",
+        }
+    }
+}
+
+/// Format of the returned `textDisplay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextFormat {
+    /// Html, the default
+    Html,
+    PlainText,
+}
+
+impl TextFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextFormat::Html => "html",
+            TextFormat::PlainText => "plainText",
+        }
+    }
+}
+
+/// Sort order of the comment threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentOrder {
+    /// Reverse chronological order, the default
+    Time,
+    Relevance,
+}
+
+impl CommentOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentOrder::Time => "time",
+            CommentOrder::Relevance => "relevance",
+        }
+    }
+}
+
+/// Filter selecting the listed threads, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum CommentThreadFilter {
+    /// Threads of a video
+    VideoId(String),
+    /// Threads about the channel itself, without those of its videos
+    ChannelId(String),
+    /// Threads of the channel and of all its videos
+    AllThreadsRelatedToChannelId(String),
+    /// Threads with the given ids
+    Id(Vec<String>),
+}
+
+/// The Api answers `id=` with an error rather than an empty list.
+fn validate_ids(ids: &[String]) -> Result<(), ClientError> {
+    if ids.is_empty() {
+        Err(ClientError::InvalidRequest(
+            "at least one comment id is required".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ListCommentThreads {
+    /// Comment thread resource properties that the API response will include
+    part: PartSet<CommentThreadPart>,
+    /// Selects the threads to list
+    filter: CommentThreadFilter,
+    /// Maximum number of items per page, from 1 to 100
+    max_results: Option<u8>,
+    /// Only returns the threads with this status, needs the owner authorization
+    moderation_status: Option<ModerationStatus>,
+    /// Sort order of the threads
+    order: Option<CommentOrder>,
+    /// Only returns the threads containing these terms
+    search_terms: Option<String>,
+    /// Format of the returned comments
+    text_format: Option<TextFormat>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListCommentThreads {
+    /// Create with the given filter, filtering by ids requires at least one
+    pub fn new(filter: CommentThreadFilter) -> Result<ListCommentThreads, ClientError> {
+        if let CommentThreadFilter::Id(ids) = &filter {
+            validate_ids(ids)?;
+        }
+        Ok(ListCommentThreads::with_filter(filter))
+    }
+    fn with_filter(filter: CommentThreadFilter) -> ListCommentThreads {
+        ListCommentThreads {
+            part: PartSet::default(),
+            filter,
+            max_results: None,
+            moderation_status: None,
+            order: None,
+            search_terms: None,
+            text_format: None,
+            page_token: None,
+            query_params: String::new(),
+        }
+    }
+    /// Create to list the threads of a video
+    pub fn create_with_video_id(video_id: &str) -> ListCommentThreads {
+        ListCommentThreads::with_filter(CommentThreadFilter::VideoId(video_id.to_string()))
+    }
+    /// Create to list the threads about a channel
+    pub fn create_with_channel_id(channel_id: &str) -> ListCommentThreads {
+        ListCommentThreads::with_filter(CommentThreadFilter::ChannelId(channel_id.to_string()))
+    }
+    /// Create to list the threads of a channel and of its videos
+    pub fn create_with_all_threads_related_to_channel_id(channel_id: &str) -> ListCommentThreads {
+        ListCommentThreads::with_filter(CommentThreadFilter::AllThreadsRelatedToChannelId(
+            channel_id.to_string(),
+        ))
+    }
+    /// Create to list the threads with the given ids, at least one is required
+    pub fn create_with_ids(ids: &[&str]) -> Result<ListCommentThreads, ClientError> {
+        ListCommentThreads::new(CommentThreadFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CommentThreadPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: CommentThreadFilter) -> Result<Self, ClientError> {
+        if let CommentThreadFilter::Id(ids) = &filter {
+            validate_ids(ids)?;
+        }
+        self.filter = filter;
+        Ok(self)
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse moderation_status to its field
+    pub fn parse_moderation_status(mut self, moderation_status: ModerationStatus) -> Self {
+        self.moderation_status = Some(moderation_status);
+        self
+    }
+    /// Parse order to its field
+    pub fn parse_order(mut self, order: CommentOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+    /// Parse search_terms to its field
+    pub fn parse_search_terms(mut self, search_terms: &str) -> Self {
+        self.search_terms = Some(search_terms.to_string());
+        self
+    }
+    /// Parse text_format to its field
+    pub fn parse_text_format(mut self, text_format: TextFormat) -> Self {
+        self.text_format = Some(text_format);
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<CommentThreadPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &CommentThreadFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            CommentThreadFilter::VideoId(video_id) => query.push("videoId", video_id),
+            CommentThreadFilter::ChannelId(channel_id) => query.push("channelId", channel_id),
+            CommentThreadFilter::AllThreadsRelatedToChannelId(channel_id) => {
+                query.push("allThreadsRelatedToChannelId", channel_id)
+            }
+            CommentThreadFilter::Id(ids) => query.push("id", ids.join(",")),
+        };
+        self.query_params = query
+            .push_opt(
+                "moderationStatus",
+                self.moderation_status
+                    .as_ref()
+                    .map(ModerationStatus::as_str),
+            )
+            .push_opt("order", self.order.as_ref().map(CommentOrder::as_str))
+            .push_opt("searchTerms", self.search_terms.as_ref())
+            .push_opt(
+                "textFormat",
+                self.text_format.as_ref().map(TextFormat::as_str),
+            )
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertCommentThreads {
+    /// Comment thread resource properties that are set and returned
+    part: PartSet<CommentThreadPart>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertCommentThreads {
+    fn default() -> Self {
+        InsertCommentThreads {
+            part: PartSet::new().with(CommentThreadPart::Snippet),
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertCommentThreads {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CommentThreadPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("part", &self.part).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+/// Filter selecting the listed comments, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum CommentFilter {
+    /// Replies to a top-level comment
+    ParentId(String),
+    /// Comments with the given ids
+    Id(Vec<String>),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ListComments {
+    /// Comment resource properties that the API response will include
+    part: PartSet<CommentPart>,
+    /// Selects the comments to list
+    filter: CommentFilter,
+    /// Maximum number of items per page, from 1 to 100
+    max_results: Option<u8>,
+    /// Format of the returned comments
+    text_format: Option<TextFormat>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListComments {
+    /// Create with the given filter, filtering by ids requires at least one
+    pub fn new(filter: CommentFilter) -> Result<ListComments, ClientError> {
+        if let CommentFilter::Id(ids) = &filter {
+            validate_ids(ids)?;
+        }
+        Ok(ListComments::with_filter(filter))
+    }
+    fn with_filter(filter: CommentFilter) -> ListComments {
+        ListComments {
+            part: PartSet::default(),
+            filter,
+            max_results: None,
+            text_format: None,
+            page_token: None,
+            query_params: String::new(),
+        }
+    }
+    /// Create to list the replies to a top-level comment
+    pub fn create_with_parent_id(parent_id: &str) -> ListComments {
+        ListComments::with_filter(CommentFilter::ParentId(parent_id.to_string()))
+    }
+    /// Create to list the comments with the given ids, at least one is required
+    pub fn create_with_ids(ids: &[&str]) -> Result<ListComments, ClientError> {
+        ListComments::new(CommentFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CommentPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: CommentFilter) -> Result<Self, ClientError> {
+        if let CommentFilter::Id(ids) = &filter {
+            validate_ids(ids)?;
+        }
+        self.filter = filter;
+        Ok(self)
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse text_format to its field
+    pub fn parse_text_format(mut self, text_format: TextFormat) -> Self {
+        self.text_format = Some(text_format);
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<CommentPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &CommentFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            CommentFilter::ParentId(parent_id) => query.push("parentId", parent_id),
+            CommentFilter::Id(ids) => query.push("id", ids.join(",")),
+        };
+        self.query_params = query
+            .push_opt(
+                "textFormat",
+                self.text_format.as_ref().map(TextFormat::as_str),
+            )
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertComments {
+    /// Comment resource properties that are set and returned
+    part: PartSet<CommentPart>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertComments {
+    fn default() -> Self {
+        InsertComments {
+            part: PartSet::new().with(CommentPart::Snippet),
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertComments {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CommentPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("part", &self.part).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpdateComments {
+    /// Comment resource properties that are updated and returned
+    part: PartSet<CommentPart>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for UpdateComments {
+    fn default() -> Self {
+        UpdateComments {
+            part: PartSet::new().with(CommentPart::Snippet),
+            query_params: String::new(),
+        }
+    }
+}
+
+impl UpdateComments {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<CommentPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("part", &self.part).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DeleteComment {
+    /// ID of the comment to be deleted
+    id: String,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DeleteComment {
+    pub fn new(id: &str) -> DeleteComment {
+        DeleteComment::default().parse_id(id)
+    }
+    /// Parse id to its field
+    pub fn parse_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+    /// Returns the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("id", &self.id).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SetModerationStatus {
+    /// IDs of the comments to moderate
+    ids: Vec<String>,
+    /// New status of the comments, `heldForReview`, `published` or `rejected`
+    moderation_status: ModerationStatus,
+    /// Also rejects the future comments of the authors, only with `rejected`
+    ban_author: bool,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl SetModerationStatus {
+    /// `likelySpam` is only set by YouTube, it cannot be requested.
+    pub fn new(
+        ids: &[&str],
+        moderation_status: ModerationStatus,
+    ) -> Result<SetModerationStatus, ClientError> {
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        validate_ids(&ids)?;
+        if moderation_status == ModerationStatus::LikelySpam {
+            return Err(ClientError::InvalidRequest(
+                "comments cannot be moderated as likelySpam".to_string(),
+            ));
+        }
+        Ok(SetModerationStatus {
+            ids,
+            moderation_status,
+            ban_author: false,
+            query_params: String::new(),
+        })
+    }
+    /// Parse ban_author to its field, only allowed when rejecting the comments
+    pub fn parse_ban_author(mut self, ban_author: bool) -> Result<Self, ClientError> {
+        if ban_author && self.moderation_status != ModerationStatus::Rejected {
+            return Err(ClientError::InvalidRequest(
+                "the author can only be banned when rejecting the comments".to_string(),
+            ));
+        }
+        self.ban_author = ban_author;
+        Ok(self)
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("id", self.ids.join(","))
+            .push("moderationStatus", self.moderation_status.as_str())
+            .push_opt("banAuthor", Some(true).filter(|_| self.ban_author))
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_comment_threads() {
+        let threads = ListCommentThreads::create_with_all_threads_related_to_channel_id("UC123")
+            .parse_part(PartSet::new().with(CommentThreadPart::Snippet))
+            .parse_moderation_status(ModerationStatus::HeldForReview)
+            .parse_order(CommentOrder::Relevance)
+            .parse_search_terms("great video")
+            .parse_text_format(TextFormat::PlainText)
+            .parse_max_results(100)
+            .build_query_parameters();
+        assert_eq!(
+            threads.get_query_params(),
+            "part=snippet&allThreadsRelatedToChannelId=UC123&moderationStatus=heldForReview\
+            &order=relevance&searchTerms=great%20video&textFormat=plainText&maxResults=100"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_replies() {
+        let comments = ListComments::create_with_parent_id("Ugx1")
+            .parse_text_format(TextFormat::Html)
+            .build_query_parameters();
+        assert_eq!(
+            comments.get_query_params(),
+            "part=id,snippet&parentId=Ugx1&textFormat=html"
+        )
+    }
+    #[test]
+    fn test_build_query_params_set_moderation_status() {
+        let moderation = SetModerationStatus::new(&["c1", "c2"], ModerationStatus::Rejected)
+            .unwrap()
+            .parse_ban_author(true)
+            .unwrap()
+            .build_query_parameters();
+        assert_eq!(
+            moderation.get_query_params(),
+            "id=c1,c2&moderationStatus=rejected&banAuthor=true"
+        );
+        let moderation = SetModerationStatus::new(&["c1"], ModerationStatus::Published)
+            .unwrap()
+            .build_query_parameters();
+        assert_eq!(
+            moderation.get_query_params(),
+            "id=c1&moderationStatus=published"
+        );
+    }
+    #[test]
+    fn test_reject_empty_ids() {
+        assert!(ListCommentThreads::create_with_ids(&[]).is_err());
+        assert!(ListCommentThreads::create_with_video_id("abc")
+            .parse_filter(CommentThreadFilter::Id(Vec::new()))
+            .is_err());
+        assert!(ListComments::create_with_ids(&[]).is_err());
+        assert!(ListComments::new(CommentFilter::Id(Vec::new())).is_err());
+        assert!(SetModerationStatus::new(&[], ModerationStatus::Published).is_err());
+        let comments = ListComments::create_with_ids(&["c1"])
+            .unwrap()
+            .build_query_parameters();
+        assert!(comments.get_query_params().ends_with("&id=c1"));
+    }
+    #[test]
+    fn test_reject_invalid_moderation() {
+        assert!(matches!(
+            SetModerationStatus::new(&["c1"], ModerationStatus::LikelySpam),
+            Err(ClientError::InvalidRequest(_))
+        ));
+        let moderation =
+            SetModerationStatus::new(&["c1"], ModerationStatus::HeldForReview).unwrap();
+        assert!(matches!(
+            moderation.parse_ban_author(true),
+            Err(ClientError::InvalidRequest(_))
+        ));
+    }
+}
@@ -1,20 +1,106 @@
+//! Errors returned by the client.
+//!
+//! [`ClientError`] does not depend on the transport, every transport turns its own failures into
+//! a [`TransportError`] reported as [`ClientError::Network`].
+
+use crate::transport::TransportError;
 use core::fmt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+/// Error returned by every endpoint.
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request could not be sent or the response could not be read
+    Network(TransportError),
+    /// The response has an error status but no error envelope
+    Status { status: u16, body: String },
+    /// The response could not be deserialized, the body is kept to investigate
+    Decode {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The Api answered with an error envelope
+    Youtube(YoutubeError),
+    /// The request was not sent as its parameters are invalid
+    InvalidRequest(String),
+}
+
+impl ClientError {
+    /// HTTP status code of the response, when the error comes from one.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::Status { status, .. } => Some(*status),
+            ClientError::Youtube(e) => Some(e.code()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Network(e) => write!(f, "network error: {}", e.message()),
+            ClientError::Status { status, .. } => write!(f, "unexpected status {}", status),
+            ClientError::Decode { source, .. } => write!(f, "cannot decode response: {}", source),
+            ClientError::Youtube(e) => write!(f, "youtube error: {}", e),
+            ClientError::InvalidRequest(message) => write!(f, "invalid request: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Network(e) => Some(e),
+            ClientError::Decode { source, .. } => Some(source),
+            ClientError::Youtube(e) => Some(e),
+            ClientError::Status { .. } | ClientError::InvalidRequest(_) => None,
+        }
+    }
+}
+
+impl From<YoutubeError> for ClientError {
+    fn from(e: YoutubeError) -> Self {
+        ClientError::Youtube(e)
+    }
+}
+
+/// Error of a request body which could not be serialized, to use with `map_err`.
+pub(crate) fn invalid_body(e: serde_json::Error) -> ClientError {
+    ClientError::InvalidRequest(format!("cannot serialize the body: {}", e))
+}
+
+impl From<TransportError> for ClientError {
+    fn from(e: TransportError) -> Self {
+        ClientError::Network(e)
+    }
+}
 
 /// Youtube Error, see https://developers.google.com/youtube/v3/docs/errors for more information.
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct YoutubeError {
     pub(crate) code: u16,
     pub(crate) message: String,
+    /// Canonical status such as `PERMISSION_DENIED`, not sent by every api
+    #[serde(default)]
+    pub(crate) status: Option<String>,
+    #[serde(default)]
+    pub(crate) errors: Vec<ErrorDetail>,
 }
 
 impl fmt::Display for YoutubeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}({})", self.code, self.message)
+        write!(f, "{}({})", self.code, self.message)?;
+        if let Some(reason) = self.reason() {
+            write!(f, " [{}]", reason)?;
+        }
+        Ok(())
     }
 }
 
+impl std::error::Error for YoutubeError {}
+
 impl YoutubeError {
     /// Get the HTTP status code of an error response.
     pub fn code(&self) -> u16 {
@@ -24,4 +110,235 @@ impl YoutubeError {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Every error reported by the response, usually only one.
+    pub fn errors(&self) -> &[ErrorDetail] {
+        &self.errors
+    }
+
+    /// Reason of the first error, the one to react to.
+    pub fn reason(&self) -> Option<&ErrorReason> {
+        self.errors.first().map(|error| &error.reason)
+    }
+
+    /// Is the reason of any of the errors the given one ?
+    pub fn has_reason(&self, reason: &ErrorReason) -> bool {
+        self.errors.iter().any(|error| &error.reason == reason)
+    }
+}
+
+/// One of the errors of the envelope.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetail {
+    #[serde(default)]
+    pub(crate) message: String,
+    /// Scope of the reason, such as `youtube.quota` or `global`
+    #[serde(default)]
+    pub(crate) domain: String,
+    pub(crate) reason: ErrorReason,
+    /// Parameter or header the error is about
+    #[serde(default)]
+    pub(crate) location: Option<String>,
+    /// Kind of location, such as `parameter` or `header`
+    #[serde(default)]
+    pub(crate) location_type: Option<String>,
+}
+
+impl ErrorDetail {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    pub fn reason(&self) -> &ErrorReason {
+        &self.reason
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    pub fn location_type(&self) -> Option<&str> {
+        self.location_type.as_deref()
+    }
+}
+
+/// Reason of an error, see https://developers.google.com/youtube/v3/docs/core_errors and the
+/// errors listed for each method.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorReason {
+    BadRequest,
+    Invalid,
+    InvalidParameter,
+    InvalidPageToken,
+    Required,
+    Unauthorized,
+    AuthError,
+    Forbidden,
+    InsufficientPermissions,
+    AccessNotConfigured,
+    KeyInvalid,
+    NotFound,
+    VideoNotFound,
+    ChannelNotFound,
+    PlaylistNotFound,
+    PlaylistItemNotFound,
+    CommentNotFound,
+    CommentThreadNotFound,
+    CaptionNotFound,
+    SubscriptionNotFound,
+    SubscriptionDuplicate,
+    CommentsDisabled,
+    VideoRatingDisabled,
+    UploadLimitExceeded,
+    QuotaExceeded,
+    DailyLimitExceeded,
+    RateLimitExceeded,
+    UserRateLimitExceeded,
+    BackendError,
+    InternalError,
+    /// Reason unknown to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ErrorReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorReason::BadRequest => "badRequest",
+            ErrorReason::Invalid => "invalid",
+            ErrorReason::InvalidParameter => "invalidParameter",
+            ErrorReason::InvalidPageToken => "invalidPageToken",
+            ErrorReason::Required => "required",
+            ErrorReason::Unauthorized => "unauthorized",
+            ErrorReason::AuthError => "authError",
+            ErrorReason::Forbidden => "forbidden",
+            ErrorReason::InsufficientPermissions => "insufficientPermissions",
+            ErrorReason::AccessNotConfigured => "accessNotConfigured",
+            ErrorReason::KeyInvalid => "keyInvalid",
+            ErrorReason::NotFound => "notFound",
+            ErrorReason::VideoNotFound => "videoNotFound",
+            ErrorReason::ChannelNotFound => "channelNotFound",
+            ErrorReason::PlaylistNotFound => "playlistNotFound",
+            ErrorReason::PlaylistItemNotFound => "playlistItemNotFound",
+            ErrorReason::CommentNotFound => "commentNotFound",
+            ErrorReason::CommentThreadNotFound => "commentThreadNotFound",
+            ErrorReason::CaptionNotFound => "captionNotFound",
+            ErrorReason::SubscriptionNotFound => "subscriptionNotFound",
+            ErrorReason::SubscriptionDuplicate => "subscriptionDuplicate",
+            ErrorReason::CommentsDisabled => "commentsDisabled",
+            ErrorReason::VideoRatingDisabled => "videoRatingDisabled",
+            ErrorReason::UploadLimitExceeded => "uploadLimitExceeded",
+            ErrorReason::QuotaExceeded => "quotaExceeded",
+            ErrorReason::DailyLimitExceeded => "dailyLimitExceeded",
+            ErrorReason::RateLimitExceeded => "rateLimitExceeded",
+            ErrorReason::UserRateLimitExceeded => "userRateLimitExceeded",
+            ErrorReason::BackendError => "backendError",
+            ErrorReason::InternalError => "internalError",
+            ErrorReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_full_envelope() {
+        let error: YoutubeError = serde_json::from_value(json!({
+            "code": 400,
+            "message": "Invalid page token",
+            "status": "INVALID_ARGUMENT",
+            "errors": [{
+                "message": "Invalid page token",
+                "domain": "youtube.parameter",
+                "reason": "invalidPageToken",
+                "location": "pageToken",
+                "locationType": "parameter"
+            }]
+        }))
+        .unwrap();
+        assert_eq!(error.status(), Some("INVALID_ARGUMENT"));
+        assert_eq!(error.reason(), Some(&ErrorReason::InvalidPageToken));
+        let detail = &error.errors()[0];
+        assert_eq!(detail.domain(), "youtube.parameter");
+        assert_eq!(detail.location(), Some("pageToken"));
+        assert_eq!(detail.location_type(), Some("parameter"));
+        assert_eq!(
+            error.to_string(),
+            "400(Invalid page token) [invalidPageToken]"
+        );
+    }
+    #[test]
+    fn test_deserialize_unknown_reason() {
+        let error: YoutubeError = serde_json::from_value(json!({
+            "code": 403,
+            "message": "Forbidden",
+            "errors": [
+                {"domain": "youtube.quota", "reason": "quotaExceeded"},
+                {"domain": "youtube.video", "reason": "somethingNew"}
+            ]
+        }))
+        .unwrap();
+        assert!(error.has_reason(&ErrorReason::QuotaExceeded));
+        let reason = error.errors()[1].reason();
+        assert_eq!(reason, &ErrorReason::Other("somethingNew".to_string()));
+        assert_eq!(reason.as_str(), "somethingNew");
+    }
+    #[test]
+    fn test_display_client_errors() {
+        let decode = serde_json::from_str::<YoutubeError>("<html>").unwrap_err();
+        let errors = vec![
+            (
+                ClientError::Network(TransportError::new("connection reset")),
+                "network error: connection reset",
+            ),
+            (
+                ClientError::Status {
+                    status: 502,
+                    body: String::new(),
+                },
+                "unexpected status 502",
+            ),
+            (
+                ClientError::InvalidRequest("invalid video id".to_string()),
+                "invalid request: invalid video id",
+            ),
+        ];
+        for (error, expected) in errors {
+            assert_eq!(error.to_string(), expected);
+        }
+        let error = ClientError::Decode {
+            source: decode,
+            body: "<html>".to_string(),
+        };
+        assert!(error.to_string().starts_with("cannot decode response: "));
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(error.status(), None);
+    }
+    #[test]
+    fn test_deserialize_without_errors() {
+        let error: YoutubeError =
+            serde_json::from_value(json!({"code": 500, "message": "Backend Error"})).unwrap();
+        assert!(error.errors().is_empty());
+        assert_eq!(error.reason(), None);
+        assert_eq!(error.to_string(), "500(Backend Error)");
+    }
 }
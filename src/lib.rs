@@ -1,13 +1,29 @@
-use seed::prelude::*;
-
+pub mod activity;
+pub mod caption;
+pub mod channel;
 pub mod client;
+pub mod comment;
 pub mod config;
 pub mod error;
 pub mod login;
+pub mod part;
+pub mod playlist;
+pub mod playlist_item;
+pub mod query;
 pub mod video;
+pub mod watermark;
+pub mod resource;
 pub mod response;
+pub mod search;
+pub mod subscription;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod thumbnail;
+pub mod transport;
+pub mod upload;
 
 pub  mod  prelude {
-    pub  use crate::{client::*, config::*, error::*, login::prelude::*, video::prelude::*};
+    pub  use crate::{activity::prelude::*, caption::prelude::*, channel::prelude::*, client::*, comment::prelude::*, config::*, error::*, login::prelude::*, part::*, playlist::prelude::*, playlist_item::prelude::*, resource::*, search::prelude::*, subscription::prelude::*, thumbnail::prelude::*, upload::prelude::*, video::prelude::*, watermark::prelude::*};
+    pub use crate::transport::{HttpRequest, HttpResponse, HttpTransport, TransportError};
 }
 
@@ -1,4 +1,5 @@
-use seed::prelude::IndexMap;
+use crate::query::decode;
+use indexmap::IndexMap;
 
 //
 #[derive(Default, Debug)]
@@ -42,7 +43,7 @@ impl AccessTokenResponse {
     }
 }
 /// Extract data from  from the url fragment and return an IndexMap
-/// for the Enum Variant.
+/// for the Enum Variant. Keys and values are percent-decoded.
 /// # Panics
 /// The function will panic a key that has no value.
 /// # Warns
@@ -62,7 +63,7 @@ pub fn extract_query_fragments(hash: String) -> IndexMap<String, String> {
         let value = sub
             .next()
             .unwrap_or_else(|| panic!("we should have a value for the key but got {}", hash));
-        query.insert(key.to_string(), value.to_string());
+        query.insert(decode(key), decode(value));
     }
     query
 }
@@ -123,6 +124,16 @@ mod tests {
         assert_eq!(token.expires_in, "testExpiresIn")
     }
     #[test]
+    fn test_extract_decodes_fragments() {
+        let hash = "state=a%26b&access_token=ya29.a0&scope=https%3A%2F%2Fwww.googleapis.com%2Fauth%2Fyoutube+https://www.googleapis.com/auth/youtube.upload";
+        let query = extract_query_fragments(hash.to_string());
+        assert_eq!(query["state"], "a&b");
+        assert_eq!(
+            query["scope"],
+            "https://www.googleapis.com/auth/youtube https://www.googleapis.com/auth/youtube.upload"
+        );
+    }
+    #[test]
     fn test_extract_scope() {
         let token = AccessTokenResponse {
             state: "testState".to_string(),
@@ -0,0 +1,117 @@
+//! Typed `part` parameter shared by every resource endpoint.
+
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::iter::FromIterator;
+
+/// A resource property that can be requested with the `part` parameter.
+pub trait Part: Copy + PartialEq + 'static {
+    /// Every part of the resource, in the order they are serialized.
+    const ALL: &'static [Self];
+    /// Returns the name expected by the Api.
+    fn as_str(&self) -> &'static str;
+    /// Is the part only returned to the owner of the resource ?
+    fn requires_owner(&self) -> bool {
+        false
+    }
+}
+
+/// Set of parts serialized as the comma-separated list expected by the Api.
+/// The default set holds every part available without owner access.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PartSet<P> {
+    parts: Vec<P>,
+}
+
+impl<P: Part> PartSet<P> {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        PartSet { parts: Vec::new() }
+    }
+    /// Create a set with every part of the resource.
+    pub fn all() -> Self {
+        P::ALL.iter().copied().collect()
+    }
+    /// Create a set with every part that does not require owner access.
+    pub fn public() -> Self {
+        P::ALL
+            .iter()
+            .copied()
+            .filter(|part| !part.requires_owner())
+            .collect()
+    }
+    /// Add a part, it is ignored if already in the set.
+    pub fn with(mut self, part: P) -> Self {
+        if !self.contains(part) {
+            self.parts.push(part);
+        }
+        self
+    }
+    /// Remove a part.
+    pub fn without(mut self, part: P) -> Self {
+        self.parts.retain(|p| *p != part);
+        self
+    }
+    /// Is the part in the set ?
+    pub fn contains(&self, part: P) -> bool {
+        self.parts.contains(&part)
+    }
+    /// Does one of the parts require owner access ?
+    pub fn requires_owner(&self) -> bool {
+        self.parts.iter().any(Part::requires_owner)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+    /// Returns the parts in the order they are serialized.
+    pub fn parts(&self) -> &[P] {
+        &self.parts
+    }
+}
+
+impl<P: Part> Default for PartSet<P> {
+    fn default() -> Self {
+        PartSet::public()
+    }
+}
+
+impl<P: Part> FromIterator<P> for PartSet<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        iter.into_iter().fold(PartSet::new(), PartSet::with)
+    }
+}
+
+impl<P: Part> fmt::Display for PartSet<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.parts.iter().map(Part::as_str).collect();
+        f.write_str(&names.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video::query::VideoPart;
+
+    #[test]
+    fn test_serialize_in_insertion_order() {
+        let parts = PartSet::new()
+            .with(VideoPart::Statistics)
+            .with(VideoPart::Snippet)
+            .with(VideoPart::Statistics);
+        assert_eq!(parts.to_string(), "statistics,snippet");
+    }
+    #[test]
+    fn test_public_parts_do_not_require_owner() {
+        let public = PartSet::<VideoPart>::public();
+        assert!(!public.requires_owner());
+        assert!(!public.contains(VideoPart::FileDetails));
+        assert!(public.with(VideoPart::FileDetails).requires_owner());
+    }
+    #[test]
+    fn test_all_parts() {
+        let all = PartSet::<VideoPart>::all().without(VideoPart::Id);
+        assert_eq!(all.parts().len(), VideoPart::ALL.len() - 1);
+        assert!(all.requires_owner());
+    }
+}
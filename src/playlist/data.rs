@@ -0,0 +1,84 @@
+use crate::resource::{Localization, PrivacyStatus, Thumbnails};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The playlist data model https://developers.google.com/youtube/v3/docs/playlists#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct YoutubePlaylist {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting a playlist
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<PlaylistSnippet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PlaylistStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_details: Option<PlaylistContentDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player: Option<PlaylistPlayer>,
+    /// Translations of the title and description, keyed by BCP-47 language code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localizations: Option<BTreeMap<String, Localization>>,
+}
+
+impl YoutubePlaylist {
+    /// Create the body of a new playlist.
+    pub fn new(title: &str, description: &str, privacy_status: PrivacyStatus) -> Self {
+        YoutubePlaylist {
+            snippet: Some(PlaylistSnippet {
+                title: title.to_string(),
+                description: description.to_string(),
+                ..Default::default()
+            }),
+            status: Some(PlaylistStatus {
+                privacy_status: Some(privacy_status),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The read-only properties are not sent back when inserting or updating.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistSnippet {
+    #[serde(skip_serializing)]
+    pub published_at: Option<String>,
+    #[serde(skip_serializing)]
+    pub channel_id: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(skip_serializing)]
+    pub thumbnails: Option<Thumbnails>,
+    #[serde(skip_serializing)]
+    pub channel_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_language: Option<String>,
+    #[serde(skip_serializing)]
+    pub localized: Option<Localization>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistStatus {
+    pub privacy_status: Option<PrivacyStatus>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistContentDetails {
+    pub item_count: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistPlayer {
+    /// `<iframe>` tag embedding the player.
+    pub embed_html: String,
+}
@@ -0,0 +1,198 @@
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::playlist::data::YoutubePlaylist;
+use crate::playlist::query::ListPlaylists;
+use crate::response::{
+    build_empty_response, build_response, paginate_items, paginate_pages, YoutubeListResponse,
+};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::playlist::{data::*, query::*, PlaylistEndPoint};
+}
+
+pub struct PlaylistEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for PlaylistEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        PlaylistEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> PlaylistEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("playlists");
+        PlaylistEndPoint { client, url }
+    }
+
+    /// Returns a collection of playlists that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlists/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<YoutubePlaylist>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the playlists of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` playlists were returned.
+    pub fn paginate(
+        &self,
+        query: ListPlaylists,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<YoutubePlaylist, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListPlaylists,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<YoutubePlaylist>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Creates a playlist and returns it with its id.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlists/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        playlist: &YoutubePlaylist,
+    ) -> Result<YoutubePlaylist, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url).json(playlist).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Modifies a playlist, the playlist must contain its id and every property of the updated
+    /// parts since the missing ones are deleted.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlists/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        playlist: &YoutubePlaylist,
+    ) -> Result<YoutubePlaylist, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Put, url).json(playlist).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Deletes a playlist.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlists/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::playlist::query::{DeletePlaylist, InsertPlaylists};
+    use crate::resource::PrivacyStatus;
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn playlist(id: &str, title: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#playlist",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "publishedAt": "2021-05-01T10:00:00Z",
+                "channelId": "UC123",
+                "title": title,
+                "description": "",
+                "channelTitle": "Rust"
+            },
+            "status": {"privacyStatus": "private"},
+            "contentDetails": {"itemCount": 3}
+        })
+    }
+
+    #[test]
+    fn test_list_playlists() {
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "playlists",
+            &[("channelId", "UC123")],
+            json(
+                200,
+                fixtures::list(
+                    "youtube#playlistListResponse",
+                    vec![playlist("PL1", "Talks")],
+                    None,
+                ),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListPlaylists::create_with_channel_id("UC123").build_query_parameters();
+        let playlists = block_on(client.playlists().list(query.get_query_params())).unwrap();
+        let playlist = &playlists.items[0];
+        assert_eq!(playlist.snippet.as_ref().unwrap().title, "Talks");
+        assert_eq!(playlist.content_details.as_ref().unwrap().item_count, 3);
+    }
+    #[test]
+    fn test_insert_playlist_sends_writable_properties() {
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "playlists",
+            json(200, playlist("PL1", "Talks").to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = InsertPlaylists::default().build_query_parameters();
+        let body = YoutubePlaylist::new("Talks", "Conference talks", PrivacyStatus::Private);
+        let playlist =
+            block_on(client.playlists().insert(query.get_query_params(), &body)).unwrap();
+        assert_eq!(playlist.id, "PL1");
+
+        let request = client.transport().last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "snippet": {"title": "Talks", "description": "Conference talks"},
+                "status": {"privacyStatus": "private"}
+            })
+        );
+    }
+    #[test]
+    fn test_delete_playlist() {
+        let transport = MockTransport::new()
+            .respond(Method::Delete, "playlists", json(204, ""))
+            .respond(
+                Method::Delete,
+                "playlists",
+                json(404, fixtures::error(404, "playlistNotFound", "Not found")),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let query = DeletePlaylist::new("PL1").build_query_parameters();
+        assert!(block_on(client.playlists().delete(query.get_query_params())).is_ok());
+        let result = block_on(client.playlists().delete(query.get_query_params()));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 404));
+    }
+}
@@ -0,0 +1,357 @@
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the playlist resource, see https://developers.google.com/youtube/v3/docs/playlists#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaylistPart {
+    Snippet,
+    Status,
+    ContentDetails,
+    Id,
+    Player,
+    Localizations,
+}
+
+impl Part for PlaylistPart {
+    const ALL: &'static [Self] = &[
+        PlaylistPart::Snippet,
+        PlaylistPart::Status,
+        PlaylistPart::ContentDetails,
+        PlaylistPart::Id,
+        PlaylistPart::Player,
+        PlaylistPart::Localizations,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            PlaylistPart::Snippet => "snippet",
+            PlaylistPart::Status => "status",
+            PlaylistPart::ContentDetails => "contentDetails",
+            PlaylistPart::Id => "id",
+            PlaylistPart::Player => "player",
+            PlaylistPart::Localizations => "localizations",
+        }
+    }
+}
+
+/// Parts written by insert and update, the others are read-only.
+fn writable_parts() -> PartSet<PlaylistPart> {
+    PartSet::new()
+        .with(PlaylistPart::Snippet)
+        .with(PlaylistPart::Status)
+}
+
+/// Filter selecting the listed playlists, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+pub enum PlaylistFilter {
+    /// Playlists of a channel
+    ChannelId(String),
+    /// Playlists with the given ids
+    Id(Vec<String>),
+    /// Playlists of the authenticated user
+    #[default]
+    Mine,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListPlaylists {
+    /// Playlist resource properties that the API response will include
+    part: PartSet<PlaylistPart>,
+    /// Selects the playlists to list
+    filter: PlaylistFilter,
+    /// Language used for the localized metadata
+    hl: Option<String>,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Channel of the content owner the request is made for
+    on_behalf_of_content_owner_channel: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListPlaylists {
+    /// Create with the given filter
+    pub fn new(filter: PlaylistFilter) -> ListPlaylists {
+        ListPlaylists::default().parse_filter(filter)
+    }
+    /// Create to list the playlists of the authenticated user
+    pub fn create_with_mine() -> ListPlaylists {
+        ListPlaylists::new(PlaylistFilter::Mine)
+    }
+    /// Create to list the playlists of a channel
+    pub fn create_with_channel_id(channel_id: &str) -> ListPlaylists {
+        ListPlaylists::new(PlaylistFilter::ChannelId(channel_id.to_string()))
+    }
+    /// Create to list the playlists with the given ids
+    pub fn create_with_ids(ids: &[&str]) -> ListPlaylists {
+        ListPlaylists::new(PlaylistFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: PlaylistFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+    /// Parse hl to its field
+    pub fn parse_hl(mut self, hl: &str) -> Self {
+        self.hl = Some(hl.to_string());
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse on_behalf_of_content_owner and on_behalf_of_content_owner_channel to their fields
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str, channel: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self.on_behalf_of_content_owner_channel = Some(channel.to_string());
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<PlaylistPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &PlaylistFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            PlaylistFilter::ChannelId(channel_id) => query.push("channelId", channel_id),
+            PlaylistFilter::Id(ids) => query.push("id", ids.join(",")),
+            PlaylistFilter::Mine => query.push("mine", true),
+        };
+        self.query_params = query
+            .push_opt("hl", self.hl.as_ref())
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .push_opt(
+                "onBehalfOfContentOwnerChannel",
+                self.on_behalf_of_content_owner_channel.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertPlaylists {
+    /// Playlist resource properties that are set and returned
+    part: PartSet<PlaylistPart>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Channel of the content owner the request is made for
+    on_behalf_of_content_owner_channel: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertPlaylists {
+    fn default() -> Self {
+        InsertPlaylists {
+            part: writable_parts(),
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertPlaylists {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse on_behalf_of_content_owner and on_behalf_of_content_owner_channel to their fields
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str, channel: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self.on_behalf_of_content_owner_channel = Some(channel.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .push_opt(
+                "onBehalfOfContentOwnerChannel",
+                self.on_behalf_of_content_owner_channel.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpdatePlaylists {
+    /// Playlist resource properties that are updated and returned
+    part: PartSet<PlaylistPart>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for UpdatePlaylists {
+    fn default() -> Self {
+        UpdatePlaylists {
+            part: writable_parts(),
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl UpdatePlaylists {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DeletePlaylist {
+    /// ID of the playlist to be deleted
+    id: String,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DeletePlaylist {
+    pub fn new(id: &str) -> DeletePlaylist {
+        DeletePlaylist::default().parse_id(id)
+    }
+    /// Parse id to its field
+    pub fn parse_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("id", &self.id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_mine() {
+        let playlists = ListPlaylists::create_with_mine().build_query_parameters();
+        assert_eq!(
+            playlists.get_query_params(),
+            "part=snippet,status,contentDetails,id,player,localizations&mine=true"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_channel_playlists() {
+        let playlists = ListPlaylists::create_with_channel_id("UC123")
+            .parse_part(PartSet::new().with(PlaylistPart::Snippet))
+            .parse_max_results(50)
+            .parse_page_token("CDIQAA")
+            .build_query_parameters();
+        assert_eq!(
+            playlists.get_query_params(),
+            "part=snippet&channelId=UC123&maxResults=50&pageToken=CDIQAA"
+        )
+    }
+    #[test]
+    fn test_build_query_params_insert_playlist() {
+        let insert = InsertPlaylists::default()
+            .parse_on_behalf_of_content_owner("owner", "UC123")
+            .build_query_parameters();
+        assert_eq!(
+            insert.get_query_params(),
+            "part=snippet,status&onBehalfOfContentOwner=owner&onBehalfOfContentOwnerChannel=UC123"
+        )
+    }
+    #[test]
+    fn test_build_query_params_update_playlist() {
+        let update = UpdatePlaylists::default().build_query_parameters();
+        assert_eq!(update.get_query_params(), "part=snippet,status")
+    }
+    #[test]
+    fn test_build_query_params_delete_playlist() {
+        let delete = DeletePlaylist::new("PL123").build_query_parameters();
+        assert_eq!(delete.get_query_params(), "id=PL123")
+    }
+}
@@ -0,0 +1,91 @@
+use crate::resource::{PrivacyStatus, ResourceId, Thumbnails};
+use serde::{Deserialize, Serialize};
+
+/// The playlist item data model https://developers.google.com/youtube/v3/docs/playlistItems#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItem {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting an item
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<PlaylistItemSnippet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_details: Option<PlaylistItemContentDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PlaylistItemStatus>,
+}
+
+impl PlaylistItem {
+    /// Create the body adding a video to a playlist.
+    pub fn new(playlist_id: &str, video_id: &str) -> Self {
+        PlaylistItem {
+            snippet: Some(PlaylistItemSnippet {
+                playlist_id: playlist_id.to_string(),
+                resource_id: ResourceId::video(video_id),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+    /// Set the zero-based position of the item in the playlist.
+    pub fn with_position(mut self, position: u32) -> Self {
+        self.snippet.get_or_insert_with(Default::default).position = Some(position);
+        self
+    }
+    /// Set the note of the item, sent with the `contentDetails` part.
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.content_details
+            .get_or_insert_with(Default::default)
+            .note = Some(note.to_string());
+        self
+    }
+}
+
+/// The read-only properties are not sent back when inserting or updating.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemSnippet {
+    #[serde(skip_serializing)]
+    pub published_at: Option<String>,
+    #[serde(skip_serializing)]
+    pub channel_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub title: String,
+    #[serde(default, skip_serializing)]
+    pub description: String,
+    #[serde(skip_serializing)]
+    pub thumbnails: Option<Thumbnails>,
+    #[serde(skip_serializing)]
+    pub channel_title: Option<String>,
+    #[serde(skip_serializing)]
+    pub video_owner_channel_title: Option<String>,
+    #[serde(skip_serializing)]
+    pub video_owner_channel_id: Option<String>,
+    pub playlist_id: String,
+    /// Zero-based position in the playlist, the item is appended when missing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    pub resource_id: ResourceId,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemContentDetails {
+    #[serde(skip_serializing)]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing)]
+    pub video_published_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemStatus {
+    pub privacy_status: Option<PrivacyStatus>,
+}
@@ -0,0 +1,239 @@
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::playlist_item::data::PlaylistItem;
+use crate::playlist_item::query::ListPlaylistItems;
+use crate::response::{
+    build_empty_response, build_response, paginate_items, paginate_pages, YoutubeListResponse,
+};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::playlist_item::{data::*, query::*, PlaylistItemEndPoint};
+}
+
+pub struct PlaylistItemEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for PlaylistItemEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        PlaylistItemEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> PlaylistItemEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("playlistItems");
+        PlaylistItemEndPoint { client, url }
+    }
+
+    /// Returns a collection of playlist items that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlistItems/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<PlaylistItem>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the items of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` items were returned.
+    pub fn paginate(
+        &self,
+        query: ListPlaylistItems,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<PlaylistItem, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListPlaylistItems,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<PlaylistItem>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Adds a resource to a playlist, at `snippet.position` when set or at the end otherwise.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlistItems/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        item: &PlaylistItem,
+    ) -> Result<PlaylistItem, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url).json(item).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Moves an item or changes its note, the item must contain its id, playlist id and resource
+    /// id. The properties of the updated parts missing from the item are deleted, so leave
+    /// `contentDetails` out of the part to keep the note when moving an item.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlistItems/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        item: &PlaylistItem,
+    ) -> Result<PlaylistItem, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Put, url).json(item).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Removes an item from its playlist.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/playlistItems/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part::PartSet;
+    use crate::playlist_item::query::{InsertPlaylistItems, PlaylistItemPart, UpdatePlaylistItems};
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn item(id: &str, video_id: &str, position: u32) -> serde_json::Value {
+        json!({
+            "kind": "youtube#playlistItem",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "publishedAt": "2021-05-01T10:00:00Z",
+                "channelId": "UC123",
+                "title": "Video",
+                "description": "",
+                "playlistId": "PL123",
+                "position": position,
+                "resourceId": {"kind": "youtube#video", "videoId": video_id}
+            },
+            "contentDetails": {"videoId": video_id, "note": "Watch first"}
+        })
+    }
+
+    #[test]
+    fn test_paginate_playlist_items() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "playlistItems",
+                json(
+                    200,
+                    fixtures::list(
+                        "youtube#playlistItemListResponse",
+                        vec![item("i1", "v1", 0)],
+                        Some("page2"),
+                    ),
+                ),
+            )
+            .respond_when(
+                Method::Get,
+                "playlistItems",
+                &[("pageToken", "page2")],
+                json(
+                    200,
+                    fixtures::list(
+                        "youtube#playlistItemListResponse",
+                        vec![item("i2", "v2", 1)],
+                        None,
+                    ),
+                ),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListPlaylistItems::create_with_playlist_id("PL123");
+        let items: Vec<_> = block_on(futures::StreamExt::collect::<Vec<_>>(
+            client.playlist_items().paginate(query, None),
+        ));
+        let video_ids: Vec<_> = items
+            .into_iter()
+            .map(|item| item.unwrap().snippet.unwrap().resource_id.video_id.unwrap())
+            .collect();
+        assert_eq!(video_ids, vec!["v1", "v2"]);
+    }
+    #[test]
+    fn test_insert_playlist_item_at_position() {
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "playlistItems",
+            json(200, item("i1", "v1", 2).to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = InsertPlaylistItems::default().build_query_parameters();
+        let body = PlaylistItem::new("PL123", "v1")
+            .with_position(2)
+            .with_note("Watch first");
+        let item = block_on(
+            client
+                .playlist_items()
+                .insert(query.get_query_params(), &body),
+        )
+        .unwrap();
+        assert_eq!(item.snippet.unwrap().position, Some(2));
+
+        let request = client.transport().last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "snippet": {
+                    "playlistId": "PL123",
+                    "position": 2,
+                    "resourceId": {"kind": "youtube#video", "videoId": "v1"}
+                },
+                "contentDetails": {"note": "Watch first"}
+            })
+        );
+    }
+    #[test]
+    fn test_move_playlist_item() {
+        let transport = MockTransport::new().respond(
+            Method::Put,
+            "playlistItems",
+            json(200, item("i1", "v1", 0).to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = UpdatePlaylistItems::default()
+            .parse_part(PartSet::new().with(PlaylistItemPart::Snippet))
+            .build_query_parameters();
+        let mut body = PlaylistItem::new("PL123", "v1").with_position(0);
+        body.id = "i1".to_string();
+        let item = block_on(
+            client
+                .playlist_items()
+                .update(query.get_query_params(), &body),
+        )
+        .unwrap();
+        assert_eq!(item.id, "i1");
+
+        let request = client.transport().last_request().unwrap();
+        assert_eq!(request.method, Method::Put);
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(body["id"], "i1");
+        assert_eq!(body["snippet"]["position"], 0);
+    }
+}
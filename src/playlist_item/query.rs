@@ -0,0 +1,325 @@
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the playlist item resource, see https://developers.google.com/youtube/v3/docs/playlistItems#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaylistItemPart {
+    Snippet,
+    ContentDetails,
+    Id,
+    Status,
+}
+
+impl Part for PlaylistItemPart {
+    const ALL: &'static [Self] = &[
+        PlaylistItemPart::Snippet,
+        PlaylistItemPart::ContentDetails,
+        PlaylistItemPart::Id,
+        PlaylistItemPart::Status,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            PlaylistItemPart::Snippet => "snippet",
+            PlaylistItemPart::ContentDetails => "contentDetails",
+            PlaylistItemPart::Id => "id",
+            PlaylistItemPart::Status => "status",
+        }
+    }
+}
+
+/// Parts written by insert and update, `contentDetails` only carries the note.
+fn writable_parts() -> PartSet<PlaylistItemPart> {
+    PartSet::new()
+        .with(PlaylistItemPart::Snippet)
+        .with(PlaylistItemPart::ContentDetails)
+}
+
+/// Filter selecting the listed items, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum PlaylistItemFilter {
+    /// Items of a playlist
+    PlaylistId(String),
+    /// Items with the given ids
+    Id(Vec<String>),
+}
+
+impl Default for PlaylistItemFilter {
+    fn default() -> Self {
+        PlaylistItemFilter::PlaylistId(String::new())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListPlaylistItems {
+    /// Playlist item resource properties that the API response will include
+    part: PartSet<PlaylistItemPart>,
+    /// Selects the items to list
+    filter: PlaylistItemFilter,
+    /// Only returns the items containing this video
+    video_id: Option<String>,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListPlaylistItems {
+    /// Create with the given filter
+    pub fn new(filter: PlaylistItemFilter) -> ListPlaylistItems {
+        ListPlaylistItems::default().parse_filter(filter)
+    }
+    /// Create to list the items of a playlist
+    pub fn create_with_playlist_id(playlist_id: &str) -> ListPlaylistItems {
+        ListPlaylistItems::new(PlaylistItemFilter::PlaylistId(playlist_id.to_string()))
+    }
+    /// Create to list the items with the given ids
+    pub fn create_with_ids(ids: &[&str]) -> ListPlaylistItems {
+        ListPlaylistItems::new(PlaylistItemFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistItemPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: PlaylistItemFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+    /// Parse video_id to its field
+    pub fn parse_video_id(mut self, video_id: &str) -> Self {
+        self.video_id = Some(video_id.to_string());
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<PlaylistItemPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &PlaylistItemFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            PlaylistItemFilter::PlaylistId(playlist_id) => query.push("playlistId", playlist_id),
+            PlaylistItemFilter::Id(ids) => query.push("id", ids.join(",")),
+        };
+        self.query_params = query
+            .push_opt("videoId", self.video_id.as_ref())
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertPlaylistItems {
+    /// Playlist item resource properties that are set and returned
+    part: PartSet<PlaylistItemPart>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertPlaylistItems {
+    fn default() -> Self {
+        InsertPlaylistItems {
+            part: writable_parts(),
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertPlaylistItems {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistItemPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpdatePlaylistItems {
+    /// Playlist item resource properties that are updated and returned
+    part: PartSet<PlaylistItemPart>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for UpdatePlaylistItems {
+    fn default() -> Self {
+        UpdatePlaylistItems {
+            part: writable_parts(),
+            on_behalf_of_content_owner: None,
+            query_params: String::new(),
+        }
+    }
+}
+
+impl UpdatePlaylistItems {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<PlaylistItemPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("part", &self.part)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DeletePlaylistItem {
+    /// ID of the playlist item to be deleted
+    id: String,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DeletePlaylistItem {
+    pub fn new(id: &str) -> DeletePlaylistItem {
+        DeletePlaylistItem::default().parse_id(id)
+    }
+    /// Parse id to its field
+    pub fn parse_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("id", &self.id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_list_playlist_items() {
+        let items = ListPlaylistItems::create_with_playlist_id("PL123")
+            .parse_part(PartSet::new().with(PlaylistItemPart::Snippet))
+            .parse_video_id("E6UTz_Doic8")
+            .parse_max_results(25)
+            .build_query_parameters();
+        assert_eq!(
+            items.get_query_params(),
+            "part=snippet&playlistId=PL123&videoId=E6UTz_Doic8&maxResults=25"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_playlist_items_by_ids() {
+        let items = ListPlaylistItems::create_with_ids(&["a", "b"]).build_query_parameters();
+        assert_eq!(
+            items.get_query_params(),
+            "part=snippet,contentDetails,id,status&id=a,b"
+        )
+    }
+    #[test]
+    fn test_build_query_params_write_playlist_items() {
+        let insert = InsertPlaylistItems::default().build_query_parameters();
+        assert_eq!(insert.get_query_params(), "part=snippet,contentDetails");
+        let update = UpdatePlaylistItems::default()
+            .parse_part(PartSet::new().with(PlaylistItemPart::Snippet))
+            .build_query_parameters();
+        assert_eq!(update.get_query_params(), "part=snippet");
+        let delete = DeletePlaylistItem::new("UEx1").build_query_parameters();
+        assert_eq!(delete.get_query_params(), "id=UEx1");
+    }
+}
@@ -0,0 +1,130 @@
+//! Query string builder percent-encoding every key and value.
+
+use core::fmt;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters kept as is: the unreserved ones of RFC 3986 and the comma separating list values.
+const QUERY_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b',');
+
+/// Percent-encode a query key or value.
+pub fn encode(component: &str) -> String {
+    utf8_percent_encode(component, QUERY_COMPONENT).to_string()
+}
+
+/// Decode a percent-encoded query key or value, `+` being a space.
+pub fn decode(component: &str) -> String {
+    percent_decode_str(&component.replace('+', " "))
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+/// Ordered list of query parameters, encoded when displayed.
+///
+/// ```
+/// use youtube_api::query::QueryString;
+///
+/// let query = QueryString::new()
+///     .push("q", "rust & wasm")
+///     .push_opt("pageToken", None::<&str>);
+/// assert_eq!(query.to_string(), "q=rust%20%26%20wasm");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct QueryString {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryString {
+    pub fn new() -> Self {
+        QueryString::default()
+    }
+    /// Parse an encoded query string such as `a=1&b=2`.
+    pub fn parse(query: &str) -> Self {
+        let pairs = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let mut split = pair.splitn(2, '=');
+                let key = decode(split.next().unwrap_or_default());
+                let value = decode(split.next().unwrap_or_default());
+                (key, value)
+            })
+            .collect();
+        QueryString { pairs }
+    }
+    /// Add a parameter.
+    pub fn push(mut self, key: &str, value: impl ToString) -> Self {
+        self.pairs.push((key.to_string(), value.to_string()));
+        self
+    }
+    /// Add a parameter only if the value is set.
+    pub fn push_opt<V: ToString>(self, key: &str, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.push(key, value),
+            None => self,
+        }
+    }
+    /// Returns the decoded value of the first parameter with the key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+    /// Returns the decoded parameters.
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.pairs
+    }
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+impl fmt::Display for QueryString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (key, value)) in self.pairs.iter().enumerate() {
+            if index > 0 {
+                f.write_str("&")?;
+            }
+            write!(f, "{}={}", encode(key), encode(value))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_reserved_characters() {
+        let query = QueryString::new()
+            .push("redirect_uri", "https://example.com/cb?from=yt&x=1")
+            .push("scope", "openid email")
+            .push("part", "snippet,status");
+        assert_eq!(
+            query.to_string(),
+            "redirect_uri=https%3A%2F%2Fexample.com%2Fcb%3Ffrom%3Dyt%26x%3D1\
+             &scope=openid%20email&part=snippet,status"
+        );
+    }
+    #[test]
+    fn test_encode_utf8() {
+        let query = QueryString::new().push("q", "crème brûlée+");
+        assert_eq!(query.to_string(), "q=cr%C3%A8me%20br%C3%BBl%C3%A9e%2B");
+    }
+    #[test]
+    fn test_parse_round_trip() {
+        let query = QueryString::new()
+            .push("q", "a&b=c d+e")
+            .push("empty", "");
+        let parsed = QueryString::parse(&query.to_string());
+        assert_eq!(parsed, query);
+        assert_eq!(parsed.get("q"), Some("a&b=c d+e"));
+        assert_eq!(QueryString::parse("scope=a+b").get("scope"), Some("a b"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Data types shared by several resources.
+
+use serde::{Deserialize, Serialize};
+
+/// Thumbnail images, keyed by size https://developers.google.com/youtube/v3/docs/thumbnails .
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Thumbnails {
+    #[serde(rename = "default", skip_serializing_if = "Option::is_none")]
+    pub default_size: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub medium: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxres: Option<Thumbnail>,
+}
+
+impl Thumbnails {
+    /// Returns the thumbnail with the best resolution available.
+    pub fn best(&self) -> Option<&Thumbnail> {
+        self.maxres
+            .as_ref()
+            .or(self.standard.as_ref())
+            .or(self.high.as_ref())
+            .or(self.medium.as_ref())
+            .or(self.default_size.as_ref())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Thumbnail {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+/// Title and description, localized or translated.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct Localization {
+    pub title: String,
+    pub description: String,
+}
+
+/// Privacy of a video or a playlist.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PrivacyStatus {
+    Private,
+    Public,
+    Unlisted,
+}
+
+/// Reference to the video, channel or playlist a resource points to, `kind` tells which id is set.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceId {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playlist_id: Option<String>,
+}
+
+impl ResourceId {
+    /// Reference a video.
+    pub fn video(video_id: &str) -> Self {
+        ResourceId {
+            kind: "youtube#video".to_string(),
+            video_id: Some(video_id.to_string()),
+            ..Default::default()
+        }
+    }
+    /// Reference a channel.
+    pub fn channel(channel_id: &str) -> Self {
+        ResourceId {
+            kind: "youtube#channel".to_string(),
+            channel_id: Some(channel_id.to_string()),
+            ..Default::default()
+        }
+    }
+    /// Reference a playlist.
+    pub fn playlist(playlist_id: &str) -> Self {
+        ResourceId {
+            kind: "youtube#playlist".to_string(),
+            playlist_id: Some(playlist_id.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// (De)serialize the 64 bits integers the Api sends as strings.
+pub(crate) mod option_u64 {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Number(u64),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&value.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<Repr>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(Repr::Number(value)) => Ok(Some(value)),
+            Some(Repr::Text(text)) => text.parse().map(Some).map_err(de::Error::custom),
+        }
+    }
+}
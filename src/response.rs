@@ -1,12 +1,11 @@
-
-
-use seed::log;
-use seed::prelude::*;
+use futures::future::{self, Future};
+use futures::stream::{self, Stream, StreamExt};
 use serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use crate::error::YoutubeError;
 
-use crate::client::ClientError;
+use crate::error::ClientError;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
 
 #[derive(Debug)]
 pub(crate) enum Response<T> {
@@ -14,9 +13,9 @@ pub(crate) enum Response<T> {
     Err(YoutubeError),
 }
 
-impl<T> Into<Result<T, YoutubeError>> for Response<T> {
-    fn into(self) -> Result<T, YoutubeError> {
-        match self {
+impl<T> From<Response<T>> for Result<T, YoutubeError> {
+    fn from(response: Response<T>) -> Self {
+        match response {
             Response::Ok(success) => Ok(success),
             Response::Err(err) => Err(err),
         }
@@ -34,7 +33,6 @@ where
         D: Deserializer<'de>,
     {
         let map = serde_json::Map::deserialize(deserializer)?;
-        log!("Deserialize normal Response: {:?}", map.clone());
         let rest = Value::Object(map.clone());
         let error = map.get("error");
 
@@ -67,27 +65,274 @@ pub struct YoutubeListResponse<T> {
 #[derive(Debug, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct YoutubePageInfo {
-    total_results: u16,
-    results_per_page: u16,
+    total_results: u32,
+    results_per_page: u32,
 }
 
-pub async fn build_response<T: 'static + DeserializeOwned + Serialize>(
-    request: Request<'_>,
+/// Send the request through the transport and deserialize the response.
+pub async fn build_response<T: DeserializeOwned, H: HttpTransport>(
+    transport: &H,
+    request: HttpRequest,
 ) -> Result<T, ClientError> {
-    let response = fetch(request).await;
-    match response {
-        Ok(res) => {
-            let response = res.text().await?;
-            deserialize_response(response.as_str())
-        }
-        Err(err) => Err(ClientError::Client(err)),
+    let response = transport.send(request).await?;
+    if !response.is_success() {
+        return build_error(&response);
+    }
+    deserialize_response(response.text().as_str())
+}
+
+/// Send the request through the transport for calls answering with an empty body on success,
+/// such as deletions.
+pub async fn build_empty_response<H: HttpTransport>(
+    transport: &H,
+    request: HttpRequest,
+) -> Result<(), ClientError> {
+    let response = transport.send(request).await?;
+    if !response.is_success() {
+        return build_error(&response);
+    }
+    let text = response.text();
+    if text.trim().is_empty() {
+        Ok(())
+    } else {
+        deserialize_response::<Value>(text.as_str()).map(|_| ())
+    }
+}
+
+/// Turn a failed response into an error, from its error envelope when it has one.
+pub(crate) fn build_error<T>(response: &HttpResponse) -> Result<T, ClientError> {
+    let body = response.text();
+    match deserialize_response::<Value>(body.as_str()) {
+        Err(ClientError::Youtube(e)) => Err(ClientError::Youtube(e)),
+        _ => Err(ClientError::Status {
+            status: response.status,
+            body,
+        }),
     }
 }
 
-fn deserialize_response<T>(text: &str) -> Result<T, ClientError>
+/// Send the request through the transport for calls answering with a media on success, such as
+/// downloads, the body is only deserialized on error.
+pub async fn build_bytes_response<H: HttpTransport>(
+    transport: &H,
+    request: HttpRequest,
+) -> Result<Vec<u8>, ClientError> {
+    let response = transport.send(request).await?;
+    if response.is_success() {
+        Ok(response.body)
+    } else {
+        build_error(&response)
+    }
+}
+
+/// Stream the pages of a list, `fetch_page` is called with the token of the page to retrieve
+/// until the last page, which has no `nextPageToken`, or an error.
+pub fn paginate_pages<'a, T, F, Fut>(
+    mut fetch_page: F,
+) -> impl Stream<Item = Result<YoutubeListResponse<T>, ClientError>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<String>) -> Fut + 'a,
+    Fut: Future<Output = Result<YoutubeListResponse<T>, ClientError>> + 'a,
+{
+    // `None` once there is no page left, `Some(None)` for the first page.
+    stream::unfold(Some(None), move |page_token: Option<Option<String>>| {
+        let page = page_token.map(&mut fetch_page);
+        async move {
+            match page?.await {
+                Ok(page) => {
+                    let next = if page.next_page_token.is_empty() {
+                        None
+                    } else {
+                        Some(Some(page.next_page_token.clone()))
+                    };
+                    Some((Ok(page), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+}
+
+/// Flatten the pages into their items, stopping after `max_items` when given.
+/// No further page is requested once the limit is reached.
+pub fn paginate_items<'a, T: 'a>(
+    pages: impl Stream<Item = Result<YoutubeListResponse<T>, ClientError>> + 'a,
+    max_items: Option<usize>,
+) -> impl Stream<Item = Result<T, ClientError>> + 'a {
+    pages
+        .flat_map(|page| match page {
+            Ok(page) => stream::iter(page.items.into_iter().map(Ok)).left_stream(),
+            Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+        })
+        .take(max_items.unwrap_or(usize::MAX))
+}
+
+pub(crate) fn deserialize_response<T>(text: &str) -> Result<T, ClientError>
 where
     T: DeserializeOwned,
 {
-    let response: Response<T> = serde_json::from_str(text)?;
+    let response: Response<T> =
+        serde_json::from_str(text).map_err(|source| ClientError::Decode {
+            source,
+            body: text.to_string(),
+        })?;
     Ok(Into::<Result<T, YoutubeError>>::into(response)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorReason;
+    use crate::testing::{fixtures, json, MockTransport};
+    use crate::transport::Method;
+    use crate::video::data::YoutubeVideo;
+    use futures::executor::block_on;
+
+    const URL: &str = "https://www.googleapis.com/youtube/v3/videos?part=snippet";
+
+    #[test]
+    fn test_deserialize_list_response() {
+        let body = fixtures::video_list(vec![fixtures::video("abc", "First")], Some("NEXT"));
+        let list: YoutubeListResponse<YoutubeVideo> = deserialize_response(&body).unwrap();
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(list.items[0].id, "abc");
+        assert_eq!(list.next_page_token, "NEXT");
+    }
+    #[test]
+    fn test_deserialize_error_envelope() {
+        let body = fixtures::error(403, "quotaExceeded", "The request cannot be completed");
+        let result: Result<YoutubeListResponse<YoutubeVideo>, ClientError> =
+            deserialize_response(&body);
+        match result {
+            Err(ClientError::Youtube(e)) => {
+                assert_eq!(e.code(), 403);
+                assert_eq!(e.message(), "The request cannot be completed");
+                assert_eq!(e.reason(), Some(&ErrorReason::QuotaExceeded));
+                assert_eq!(e.errors()[0].domain(), "youtube.api");
+            }
+            other => panic!("expected a Youtube error, got {:?}", other),
+        }
+    }
+    #[test]
+    fn test_deserialize_malformed_body() {
+        let result: Result<YoutubeVideo, ClientError> = deserialize_response("<html></html>");
+        assert!(matches!(result, Err(ClientError::Decode { body, .. }) if body == "<html></html>"));
+    }
+    #[test]
+    fn test_build_response_through_transport() {
+        let transport = MockTransport::new().respond(
+            Method::Get,
+            "videos",
+            json(200, fixtures::video_list(vec![fixtures::video("abc", "First")], None)),
+        );
+        let request = HttpRequest::new(Method::Get, URL);
+        let list: YoutubeListResponse<YoutubeVideo> =
+            block_on(build_response(&transport, request)).unwrap();
+        assert_eq!(list.items[0].snippet.as_ref().unwrap().title, "First");
+        assert_eq!(transport.last_request().unwrap().url, URL);
+    }
+    #[test]
+    fn test_build_response_with_error_status() {
+        let transport = MockTransport::new().respond(
+            Method::Get,
+            "videos",
+            json(404, fixtures::error(404, "videoNotFound", "Video not found")),
+        );
+        let request = HttpRequest::new(Method::Get, URL);
+        let result: Result<YoutubeListResponse<YoutubeVideo>, ClientError> =
+            block_on(build_response(&transport, request));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 404));
+    }
+    #[test]
+    fn test_build_empty_response() {
+        let transport = MockTransport::new()
+            .respond(Method::Delete, "videos", json(204, ""))
+            .respond(
+                Method::Delete,
+                "videos",
+                json(403, fixtures::error(403, "forbidden", "Forbidden")),
+            );
+        let request = HttpRequest::new(Method::Delete, URL);
+        assert!(block_on(build_empty_response(&transport, request.clone())).is_ok());
+        let result = block_on(build_empty_response(&transport, request));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 403));
+    }
+    #[test]
+    fn test_build_empty_response_without_envelope() {
+        let transport = MockTransport::new()
+            .respond(Method::Delete, "videos", json(500, "{}"))
+            .respond(
+                Method::Delete,
+                "videos",
+                HttpResponse::new(502, b"<html>Bad Gateway</html>".to_vec()),
+            );
+        let request = HttpRequest::new(Method::Delete, URL);
+        let result = block_on(build_empty_response(&transport, request.clone()));
+        assert!(matches!(result, Err(ClientError::Status { status: 500, body }) if body == "{}"));
+        let result = block_on(build_empty_response(&transport, request));
+        assert!(matches!(result, Err(ClientError::Status { status: 502, .. })));
+    }
+    #[test]
+    fn test_build_error_without_envelope() {
+        let transport = MockTransport::new().respond(
+            Method::Get,
+            "videos",
+            HttpResponse::new(502, b"<html>Bad Gateway</html>".to_vec()),
+        );
+        let request = HttpRequest::new(Method::Get, URL);
+        let result: Result<YoutubeListResponse<YoutubeVideo>, ClientError> =
+            block_on(build_response(&transport, request));
+        match result {
+            Err(ClientError::Status { status, body }) => {
+                assert_eq!(status, 502);
+                assert_eq!(body, "<html>Bad Gateway</html>");
+            }
+            other => panic!("expected a status error, got {:?}", other),
+        }
+    }
+    #[test]
+    fn test_build_bytes_response() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "captions",
+                HttpResponse::new(200, b"1\n".to_vec()),
+            )
+            .respond(
+                Method::Get,
+                "captions",
+                json(404, fixtures::error(404, "captionNotFound", "Not found")),
+            );
+        let request = HttpRequest::new(
+            Method::Get,
+            "https://www.googleapis.com/youtube/v3/captions",
+        );
+        let bytes = block_on(build_bytes_response(&transport, request.clone())).unwrap();
+        assert_eq!(bytes, b"1\n");
+        let result = block_on(build_bytes_response(&transport, request));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 404));
+    }
+    #[test]
+    fn test_build_response_follows_pages() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "videos",
+                json(200, fixtures::video_list(vec![fixtures::video("a", "A")], Some("PAGE2"))),
+            )
+            .respond_when(
+                Method::Get,
+                "videos",
+                &[("pageToken", "PAGE2")],
+                json(200, fixtures::video_list(vec![fixtures::video("b", "B")], None)),
+            );
+        let first: YoutubeListResponse<YoutubeVideo> =
+            block_on(build_response(&transport, HttpRequest::new(Method::Get, URL))).unwrap();
+        let url = format!("{}&pageToken={}", URL, first.next_page_token);
+        let second: YoutubeListResponse<YoutubeVideo> =
+            block_on(build_response(&transport, HttpRequest::new(Method::Get, url))).unwrap();
+        assert_eq!(second.items[0].id, "b");
+        assert!(second.next_page_token.is_empty());
+    }
+}
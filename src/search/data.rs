@@ -0,0 +1,78 @@
+use crate::resource::Thumbnails;
+use serde::{Deserialize, Serialize};
+
+/// The search result data model https://developers.google.com/youtube/v3/docs/search#resource
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub kind: String,
+    pub etag: String,
+    pub id: SearchResultId,
+    pub snippet: Option<SearchResultSnippet>,
+}
+
+/// Id of the resource matching the search, the variant tells its type.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind")]
+pub enum SearchResultId {
+    #[serde(rename = "youtube#video", rename_all = "camelCase")]
+    Video { video_id: String },
+    #[serde(rename = "youtube#channel", rename_all = "camelCase")]
+    Channel { channel_id: String },
+    #[serde(rename = "youtube#playlist", rename_all = "camelCase")]
+    Playlist { playlist_id: String },
+}
+
+impl SearchResultId {
+    /// Returns the id whatever the type of the resource.
+    pub fn id(&self) -> &str {
+        match self {
+            SearchResultId::Video { video_id } => video_id,
+            SearchResultId::Channel { channel_id } => channel_id,
+            SearchResultId::Playlist { playlist_id } => playlist_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResultSnippet {
+    pub published_at: String,
+    pub channel_id: String,
+    pub title: String,
+    pub description: String,
+    pub thumbnails: Option<Thumbnails>,
+    pub channel_title: String,
+    /// `upcoming`, `live` or `none`
+    pub live_broadcast_content: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    #[test]
+    fn test_deserialize_search_result_ids() {
+        let ids: Vec<SearchResultId> = serde_json::from_value(json!([
+            {"kind": "youtube#video", "videoId": "E6UTz_Doic8"},
+            {"kind": "youtube#channel", "channelId": "UC123"},
+            {"kind": "youtube#playlist", "playlistId": "PL123"}
+        ]))
+        .unwrap();
+        assert_eq!(
+            ids,
+            vec![
+                SearchResultId::Video {
+                    video_id: "E6UTz_Doic8".to_string()
+                },
+                SearchResultId::Channel {
+                    channel_id: "UC123".to_string()
+                },
+                SearchResultId::Playlist {
+                    playlist_id: "PL123".to_string()
+                },
+            ]
+        );
+        assert_eq!(ids[2].id(), "PL123");
+    }
+}
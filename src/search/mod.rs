@@ -0,0 +1,125 @@
+use crate::client::{Client, ClientError};
+use crate::response::{build_response, paginate_items, paginate_pages, YoutubeListResponse};
+use crate::search::data::SearchResult;
+use crate::search::query::ListSearchResults;
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::search::{data::*, query::*, SearchEndPoint};
+}
+
+pub struct SearchEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for SearchEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        SearchEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> SearchEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("search");
+        SearchEndPoint { client, url }
+    }
+
+    /// Returns the videos, channels and playlists matching the search.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/search/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<SearchResult>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the results of every page of the search, following `nextPageToken` until the last
+    /// page or until `max_items` results were returned.
+    pub fn paginate(
+        &self,
+        query: ListSearchResults,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<SearchResult, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the search, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListSearchResults,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<SearchResult>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::data::SearchResultId;
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    #[test]
+    fn test_search_returns_every_kind_of_result() {
+        let result = |id: serde_json::Value| {
+            json!({
+                "kind": "youtube#searchResult",
+                "etag": "etag",
+                "id": id,
+                "snippet": {
+                    "publishedAt": "2021-05-01T10:00:00Z",
+                    "channelId": "UC123",
+                    "title": "Rust",
+                    "description": "",
+                    "channelTitle": "Rust",
+                    "liveBroadcastContent": "none"
+                }
+            })
+        };
+        let items = vec![
+            result(json!({"kind": "youtube#video", "videoId": "E6UTz_Doic8"})),
+            result(json!({"kind": "youtube#channel", "channelId": "UC123"})),
+        ];
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "search",
+            &[("q", "rust")],
+            json(
+                200,
+                fixtures::list("youtube#searchListResponse", items, None),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListSearchResults::new("rust").build_query_parameters();
+        let results = block_on(client.search().list(query.get_query_params())).unwrap();
+        assert!(matches!(
+            &results.items[0].id,
+            SearchResultId::Video { video_id } if video_id == "E6UTz_Doic8"
+        ));
+        assert!(matches!(
+            &results.items[1].id,
+            SearchResultId::Channel { channel_id } if channel_id == "UC123"
+        ));
+    }
+}
@@ -0,0 +1,384 @@
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// Type of resource returned by the search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchType {
+    Video,
+    Channel,
+    Playlist,
+}
+
+impl SearchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Video => "video",
+            SearchType::Channel => "channel",
+            SearchType::Playlist => "playlist",
+        }
+    }
+}
+
+/// Sort order of the results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchOrder {
+    /// Reverse chronological order of creation
+    Date,
+    /// Highest to lowest rating
+    Rating,
+    /// Relevance to the search query, the default
+    Relevance,
+    /// Alphabetical order of the titles
+    Title,
+    /// Channels by descending number of uploaded videos
+    VideoCount,
+    /// Highest to lowest number of views
+    ViewCount,
+}
+
+impl SearchOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchOrder::Date => "date",
+            SearchOrder::Rating => "rating",
+            SearchOrder::Relevance => "relevance",
+            SearchOrder::Title => "title",
+            SearchOrder::VideoCount => "videoCount",
+            SearchOrder::ViewCount => "viewCount",
+        }
+    }
+}
+
+/// Filtering of restricted content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SafeSearch {
+    Moderate,
+    None,
+    Strict,
+}
+
+impl SafeSearch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SafeSearch::Moderate => "moderate",
+            SafeSearch::None => "none",
+            SafeSearch::Strict => "strict",
+        }
+    }
+}
+
+/// Duration of the returned videos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VideoDuration {
+    Any,
+    /// Longer than 20 minutes
+    Long,
+    /// Between 4 and 20 minutes
+    Medium,
+    /// Shorter than 4 minutes
+    Short,
+}
+
+impl VideoDuration {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoDuration::Any => "any",
+            VideoDuration::Long => "long",
+            VideoDuration::Medium => "medium",
+            VideoDuration::Short => "short",
+        }
+    }
+}
+
+/// Definition of the returned videos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VideoDefinition {
+    Any,
+    High,
+    Standard,
+}
+
+impl VideoDefinition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoDefinition::Any => "any",
+            VideoDefinition::High => "high",
+            VideoDefinition::Standard => "standard",
+        }
+    }
+}
+
+/// State of the returned broadcasts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventType {
+    Completed,
+    Live,
+    Upcoming,
+}
+
+impl EventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::Completed => "completed",
+            EventType::Live => "live",
+            EventType::Upcoming => "upcoming",
+        }
+    }
+}
+
+/// Circular area the videos were recorded in.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SearchLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Radius with its unit, `m`, `km`, `ft` or `mi`, at most 1000 kilometers
+    pub radius: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListSearchResults {
+    /// Query term to search for
+    q: Option<String>,
+    /// Types of resources to return, every type when empty
+    types: Vec<SearchType>,
+    /// Only returns the resources created by this channel
+    channel_id: Option<String>,
+    /// Sort order of the results
+    order: Option<SearchOrder>,
+    /// Only returns the resources created at or after this RFC 3339 date-time
+    published_after: Option<String>,
+    /// Only returns the resources created before or at this RFC 3339 date-time
+    published_before: Option<String>,
+    /// Returns the results for this ISO 3166-1 alpha-2 country code
+    region_code: Option<String>,
+    /// Returns the results most relevant to this ISO 639-1 language
+    relevance_language: Option<String>,
+    /// Filtering of restricted content
+    safe_search: Option<SafeSearch>,
+    /// Only for videos, duration of the returned videos
+    video_duration: Option<VideoDuration>,
+    /// Only for videos, definition of the returned videos
+    video_definition: Option<VideoDefinition>,
+    /// Only for videos, state of the returned broadcasts
+    event_type: Option<EventType>,
+    /// Only for videos, area the videos were recorded in
+    location: Option<SearchLocation>,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListSearchResults {
+    /// Create to search the given query term
+    pub fn new(q: &str) -> ListSearchResults {
+        ListSearchResults::default().parse_q(q)
+    }
+    /// Parse q to its field
+    pub fn parse_q(mut self, q: &str) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+    /// Parse search_type to the types, a search can return several types, replaced by `video`
+    /// when a video filter is set
+    pub fn parse_type(mut self, search_type: SearchType) -> Self {
+        if !self.types.contains(&search_type) {
+            self.types.push(search_type);
+        }
+        self
+    }
+    /// Parse channel_id to its field
+    pub fn parse_channel_id(mut self, channel_id: &str) -> Self {
+        self.channel_id = Some(channel_id.to_string());
+        self
+    }
+    /// Parse order to its field
+    pub fn parse_order(mut self, order: SearchOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+    /// Parse published_after to its field
+    pub fn parse_published_after(mut self, published_after: &str) -> Self {
+        self.published_after = Some(published_after.to_string());
+        self
+    }
+    /// Parse published_before to its field
+    pub fn parse_published_before(mut self, published_before: &str) -> Self {
+        self.published_before = Some(published_before.to_string());
+        self
+    }
+    /// Parse region_code to its field
+    pub fn parse_region_code(mut self, region_code: &str) -> Self {
+        self.region_code = Some(region_code.to_string());
+        self
+    }
+    /// Parse relevance_language to its field
+    pub fn parse_relevance_language(mut self, relevance_language: &str) -> Self {
+        self.relevance_language = Some(relevance_language.to_string());
+        self
+    }
+    /// Parse safe_search to its field
+    pub fn parse_safe_search(mut self, safe_search: SafeSearch) -> Self {
+        self.safe_search = Some(safe_search);
+        self
+    }
+    /// Parse video_duration to its field
+    pub fn parse_video_duration(mut self, video_duration: VideoDuration) -> Self {
+        self.video_duration = Some(video_duration);
+        self
+    }
+    /// Parse video_definition to its field
+    pub fn parse_video_definition(mut self, video_definition: VideoDefinition) -> Self {
+        self.video_definition = Some(video_definition);
+        self
+    }
+    /// Parse event_type to its field
+    pub fn parse_event_type(mut self, event_type: EventType) -> Self {
+        self.event_type = Some(event_type);
+        self
+    }
+    /// Parse the location and its radius to the location field
+    pub fn parse_location(mut self, latitude: f64, longitude: f64, radius: &str) -> Self {
+        self.location = Some(SearchLocation {
+            latitude,
+            longitude,
+            radius: radius.to_string(),
+        });
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the types
+    pub fn get_types(&self) -> &[SearchType] {
+        &self.types
+    }
+    /// Whether a filter only applying to videos is set
+    fn has_video_filter(&self) -> bool {
+        self.video_duration.is_some()
+            || self.video_definition.is_some()
+            || self.event_type.is_some()
+            || self.location.is_some()
+    }
+    /// Build and assign the query parameters, the type is only `video` when a video filter is set
+    /// since the Api rejects them with any other type.
+    pub fn build_query_parameters(mut self) -> Self {
+        let types = if self.has_video_filter() {
+            vec![SearchType::Video]
+        } else {
+            self.types.clone()
+        };
+        let types = types
+            .iter()
+            .map(SearchType::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = QueryString::new()
+            .push("part", "snippet")
+            .push_opt("q", self.q.as_ref())
+            .push_opt("type", Some(types).filter(|types| !types.is_empty()))
+            .push_opt("channelId", self.channel_id.as_ref())
+            .push_opt("order", self.order.as_ref().map(SearchOrder::as_str))
+            .push_opt("publishedAfter", self.published_after.as_ref())
+            .push_opt("publishedBefore", self.published_before.as_ref())
+            .push_opt("regionCode", self.region_code.as_ref())
+            .push_opt("relevanceLanguage", self.relevance_language.as_ref())
+            .push_opt(
+                "safeSearch",
+                self.safe_search.as_ref().map(SafeSearch::as_str),
+            )
+            .push_opt(
+                "videoDuration",
+                self.video_duration.as_ref().map(VideoDuration::as_str),
+            )
+            .push_opt(
+                "videoDefinition",
+                self.video_definition.as_ref().map(VideoDefinition::as_str),
+            )
+            .push_opt("eventType", self.event_type.as_ref().map(EventType::as_str));
+        let query = match &self.location {
+            Some(location) => query
+                .push(
+                    "location",
+                    format!("{},{}", location.latitude, location.longitude),
+                )
+                .push("locationRadius", &location.radius),
+            None => query,
+        };
+        self.query_params = query
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_search() {
+        let search = ListSearchResults::new("rust lang")
+            .parse_type(SearchType::Channel)
+            .parse_type(SearchType::Playlist)
+            .parse_type(SearchType::Channel)
+            .parse_order(SearchOrder::ViewCount)
+            .parse_published_after("2021-01-01T00:00:00Z")
+            .parse_region_code("FR")
+            .parse_relevance_language("fr")
+            .parse_safe_search(SafeSearch::Strict)
+            .parse_max_results(10)
+            .build_query_parameters();
+        assert_eq!(
+            search.get_query_params(),
+            "part=snippet&q=rust%20lang&type=channel,playlist&order=viewCount\
+            &publishedAfter=2021-01-01T00%3A00%3A00Z&regionCode=FR&relevanceLanguage=fr\
+            &safeSearch=strict&maxResults=10"
+        )
+    }
+    #[test]
+    fn test_build_query_params_video_filters_imply_video_type() {
+        let search = ListSearchResults::new("concert")
+            .parse_video_duration(VideoDuration::Long)
+            .parse_video_definition(VideoDefinition::High)
+            .parse_event_type(EventType::Live)
+            .parse_location(37.42307, -122.08427, "10km")
+            .build_query_parameters();
+        assert_eq!(
+            search.get_query_params(),
+            "part=snippet&q=concert&type=video&videoDuration=long&videoDefinition=high\
+            &eventType=live&location=37.42307,-122.08427&locationRadius=10km"
+        )
+    }
+    #[test]
+    fn test_build_query_params_video_filters_override_other_types() {
+        let search = ListSearchResults::new("concert")
+            .parse_type(SearchType::Channel)
+            .parse_type(SearchType::Playlist)
+            .parse_video_duration(VideoDuration::Short)
+            .build_query_parameters();
+        assert_eq!(
+            search.get_query_params(),
+            "part=snippet&q=concert&type=video&videoDuration=short"
+        );
+    }
+}
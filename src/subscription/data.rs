@@ -0,0 +1,74 @@
+use crate::resource::{ResourceId, Thumbnails};
+use serde::{Deserialize, Serialize};
+
+/// The subscription data model https://developers.google.com/youtube/v3/docs/subscriptions#resource
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub etag: String,
+    /// Empty when inserting a subscription
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<SubscriptionSnippet>,
+    #[serde(skip_serializing)]
+    pub content_details: Option<SubscriptionContentDetails>,
+    #[serde(skip_serializing)]
+    pub subscriber_snippet: Option<SubscriberSnippet>,
+}
+
+impl Subscription {
+    /// Create the body subscribing the authenticated user to a channel.
+    pub fn new(channel_id: &str) -> Self {
+        Subscription {
+            snippet: Some(SubscriptionSnippet {
+                resource_id: ResourceId::channel(channel_id),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The read-only properties are not sent back when inserting.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionSnippet {
+    #[serde(skip_serializing)]
+    pub published_at: Option<String>,
+    /// Title of the channel subscribed to
+    #[serde(default, skip_serializing)]
+    pub title: String,
+    #[serde(default, skip_serializing)]
+    pub description: String,
+    /// Channel subscribed to
+    pub resource_id: ResourceId,
+    /// Channel of the subscriber
+    #[serde(skip_serializing)]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing)]
+    pub channel_title: Option<String>,
+    #[serde(skip_serializing)]
+    pub thumbnails: Option<Thumbnails>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionContentDetails {
+    pub total_item_count: u32,
+    pub new_item_count: u32,
+    /// `all` or `uploads`
+    pub activity_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriberSnippet {
+    pub title: String,
+    pub description: String,
+    pub channel_id: String,
+    pub thumbnails: Option<Thumbnails>,
+}
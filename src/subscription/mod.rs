@@ -0,0 +1,194 @@
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::response::{
+    build_empty_response, build_response, paginate_items, paginate_pages, YoutubeListResponse,
+};
+use crate::subscription::data::Subscription;
+use crate::subscription::query::ListSubscriptions;
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use futures::Stream;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::subscription::{data::*, query::*, SubscriptionEndPoint};
+}
+
+pub struct SubscriptionEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for SubscriptionEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        SubscriptionEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> SubscriptionEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("subscriptions");
+        SubscriptionEndPoint { client, url }
+    }
+
+    /// Returns the subscriptions that match the request criteria.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/subscriptions/list .
+    pub async fn list(
+        &self,
+        query_search: &str,
+    ) -> Result<YoutubeListResponse<Subscription>, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the subscriptions of every page of the list, following `nextPageToken` until the
+    /// last page or until `max_items` subscriptions were returned.
+    pub fn paginate(
+        &self,
+        query: ListSubscriptions,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<Subscription, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListSubscriptions,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<Subscription>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
+    }
+
+    /// Subscribes the authenticated user to the channel of `snippet.resourceId`.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/subscriptions/insert .
+    pub async fn insert(
+        &self,
+        query_search: &str,
+        subscription: &Subscription,
+    ) -> Result<Subscription, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url).json(subscription).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Deletes a subscription.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/subscriptions/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::query::{DeleteSubscription, InsertSubscriptions};
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn subscription(id: &str, channel_id: &str) -> serde_json::Value {
+        json!({
+            "kind": "youtube#subscription",
+            "etag": "etag",
+            "id": id,
+            "snippet": {
+                "publishedAt": "2021-05-01T10:00:00Z",
+                "title": "Rust",
+                "description": "",
+                "resourceId": {"kind": "youtube#channel", "channelId": channel_id},
+                "channelId": "UCme"
+            },
+            "contentDetails": {"totalItemCount": 120, "newItemCount": 2, "activityType": "all"}
+        })
+    }
+
+    #[test]
+    fn test_list_my_subscriptions() {
+        let transport = MockTransport::new().respond_when(
+            Method::Get,
+            "subscriptions",
+            &[("mine", "true"), ("forChannelId", "UC123")],
+            json(
+                200,
+                fixtures::list(
+                    "youtube#subscriptionListResponse",
+                    vec![subscription("s1", "UC123")],
+                    None,
+                ),
+            ),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListSubscriptions::create_with_mine()
+            .parse_for_channel_id(&["UC123"])
+            .build_query_parameters();
+        let subscriptions =
+            block_on(client.subscriptions().list(query.get_query_params())).unwrap();
+        let subscription = &subscriptions.items[0];
+        assert_eq!(
+            subscription
+                .snippet
+                .as_ref()
+                .unwrap()
+                .resource_id
+                .channel_id
+                .as_deref(),
+            Some("UC123")
+        );
+        assert_eq!(
+            subscription
+                .content_details
+                .as_ref()
+                .unwrap()
+                .new_item_count,
+            2
+        );
+    }
+    #[test]
+    fn test_subscribe_and_unsubscribe() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Post,
+                "subscriptions",
+                json(200, subscription("s1", "UC123").to_string()),
+            )
+            .respond(Method::Delete, "subscriptions", json(204, ""));
+        let client = Client::with_transport("token", "key", transport);
+
+        let query = InsertSubscriptions::default().build_query_parameters();
+        let body = Subscription::new("UC123");
+        let subscription = block_on(
+            client
+                .subscriptions()
+                .insert(query.get_query_params(), &body),
+        )
+        .unwrap();
+        let request = client.transport().last_request().unwrap();
+        let sent: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            sent,
+            json!({"snippet": {"resourceId": {"kind": "youtube#channel", "channelId": "UC123"}}})
+        );
+
+        let query = DeleteSubscription::new(&subscription.id).build_query_parameters();
+        block_on(client.subscriptions().delete(query.get_query_params())).unwrap();
+        let request = client.transport().last_request().unwrap();
+        assert!(request.url.ends_with("&id=s1"));
+    }
+}
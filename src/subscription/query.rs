@@ -0,0 +1,326 @@
+use crate::client::ClientError;
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// The parts of the subscription resource, see https://developers.google.com/youtube/v3/docs/subscriptions#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionPart {
+    Snippet,
+    ContentDetails,
+    Id,
+    SubscriberSnippet,
+}
+
+impl Part for SubscriptionPart {
+    const ALL: &'static [Self] = &[
+        SubscriptionPart::Snippet,
+        SubscriptionPart::ContentDetails,
+        SubscriptionPart::Id,
+        SubscriptionPart::SubscriberSnippet,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SubscriptionPart::Snippet => "snippet",
+            SubscriptionPart::ContentDetails => "contentDetails",
+            SubscriptionPart::Id => "id",
+            SubscriptionPart::SubscriberSnippet => "subscriberSnippet",
+        }
+    }
+}
+
+/// Sort order of the subscriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionOrder {
+    Alphabetical,
+    /// The default
+    Relevance,
+    /// Order of the activity of the channels
+    Unread,
+}
+
+impl SubscriptionOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubscriptionOrder::Alphabetical => "alphabetical",
+            SubscriptionOrder::Relevance => "relevance",
+            SubscriptionOrder::Unread => "unread",
+        }
+    }
+}
+
+/// Filter selecting the listed subscriptions, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+pub enum SubscriptionFilter {
+    /// Subscriptions of a channel
+    ChannelId(String),
+    /// Subscriptions with the given ids
+    Id(Vec<String>),
+    /// Subscriptions of the authenticated user
+    #[default]
+    Mine,
+    /// Latest subscribers of the authenticated user, in reverse chronological order
+    MyRecentSubscribers,
+    /// Subscribers of the authenticated user, in no particular order
+    MySubscribers,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ListSubscriptions {
+    /// Subscription resource properties that the API response will include
+    part: PartSet<SubscriptionPart>,
+    /// Selects the subscriptions to list
+    filter: SubscriptionFilter,
+    /// Only returns the subscriptions to these channels
+    for_channel_id: Vec<String>,
+    /// Maximum number of items per page, from 0 to 50
+    max_results: Option<u8>,
+    /// Sort order of the subscriptions
+    order: Option<SubscriptionOrder>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Channel of the content owner the request is made for
+    on_behalf_of_content_owner_channel: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl ListSubscriptions {
+    /// Create with the given filter, filtering by ids requires at least one
+    pub fn new(filter: SubscriptionFilter) -> Result<ListSubscriptions, ClientError> {
+        ListSubscriptions::default().parse_filter(filter)
+    }
+    fn with_filter(filter: SubscriptionFilter) -> ListSubscriptions {
+        ListSubscriptions {
+            filter,
+            ..ListSubscriptions::default()
+        }
+    }
+    /// Create to list the subscriptions of the authenticated user
+    pub fn create_with_mine() -> ListSubscriptions {
+        ListSubscriptions::with_filter(SubscriptionFilter::Mine)
+    }
+    /// Create to list the latest subscribers of the authenticated user
+    pub fn create_with_my_recent_subscribers() -> ListSubscriptions {
+        ListSubscriptions::with_filter(SubscriptionFilter::MyRecentSubscribers)
+    }
+    /// Create to list the subscribers of the authenticated user
+    pub fn create_with_my_subscribers() -> ListSubscriptions {
+        ListSubscriptions::with_filter(SubscriptionFilter::MySubscribers)
+    }
+    /// Create to list the subscriptions of a channel
+    pub fn create_with_channel_id(channel_id: &str) -> ListSubscriptions {
+        ListSubscriptions::with_filter(SubscriptionFilter::ChannelId(channel_id.to_string()))
+    }
+    /// Create to list the subscriptions with the given ids, at least one is required
+    pub fn create_with_ids(ids: &[&str]) -> Result<ListSubscriptions, ClientError> {
+        ListSubscriptions::new(SubscriptionFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<SubscriptionPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: SubscriptionFilter) -> Result<Self, ClientError> {
+        if filter == SubscriptionFilter::Id(Vec::new()) {
+            return Err(ClientError::InvalidRequest(
+                "at least one subscription id is required".to_string(),
+            ));
+        }
+        self.filter = filter;
+        Ok(self)
+    }
+    /// Parse for_channel_id to its field, useful to check whether the user subscribed to channels
+    pub fn parse_for_channel_id(mut self, channel_ids: &[&str]) -> Self {
+        self.for_channel_id = channel_ids.iter().map(|id| id.to_string()).collect();
+        self
+    }
+    /// Parse max_results to its field
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+    /// Parse order to its field
+    pub fn parse_order(mut self, order: SubscriptionOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+    /// Parse on_behalf_of_content_owner and on_behalf_of_content_owner_channel to their fields
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str, channel: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self.on_behalf_of_content_owner_channel = Some(channel.to_string());
+        self
+    }
+    /// Parse page_token to its field
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<SubscriptionPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &SubscriptionFilter {
+        &self.filter
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        let query = QueryString::new().push("part", &self.part);
+        let query = match &self.filter {
+            SubscriptionFilter::ChannelId(channel_id) => query.push("channelId", channel_id),
+            SubscriptionFilter::Id(ids) => query.push("id", ids.join(",")),
+            SubscriptionFilter::Mine => query.push("mine", true),
+            SubscriptionFilter::MyRecentSubscribers => query.push("myRecentSubscribers", true),
+            SubscriptionFilter::MySubscribers => query.push("mySubscribers", true),
+        };
+        let for_channel_id = Some(self.for_channel_id.join(",")).filter(|ids| !ids.is_empty());
+        self.query_params = query
+            .push_opt("forChannelId", for_channel_id)
+            .push_opt("order", self.order.as_ref().map(SubscriptionOrder::as_str))
+            .push_opt("maxResults", self.max_results)
+            .push_opt("pageToken", self.page_token.as_ref())
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .push_opt(
+                "onBehalfOfContentOwnerChannel",
+                self.on_behalf_of_content_owner_channel.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InsertSubscriptions {
+    /// Subscription resource properties that are set and returned
+    part: PartSet<SubscriptionPart>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl Default for InsertSubscriptions {
+    fn default() -> Self {
+        InsertSubscriptions {
+            part: PartSet::new().with(SubscriptionPart::Snippet),
+            query_params: String::new(),
+        }
+    }
+}
+
+impl InsertSubscriptions {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<SubscriptionPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("part", &self.part).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct DeleteSubscription {
+    /// ID of the subscription to be deleted
+    id: String,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl DeleteSubscription {
+    pub fn new(id: &str) -> DeleteSubscription {
+        DeleteSubscription::default().parse_id(id)
+    }
+    /// Parse id to its field
+    pub fn parse_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+    /// Returns the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new().push("id", &self.id).to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_reject_empty_ids() {
+        assert!(matches!(
+            ListSubscriptions::create_with_ids(&[]),
+            Err(ClientError::InvalidRequest(_))
+        ));
+        let subscriptions = ListSubscriptions::create_with_ids(&["s1", "s2"])
+            .unwrap()
+            .build_query_parameters();
+        assert!(subscriptions.get_query_params().ends_with("&id=s1,s2"));
+    }
+    #[test]
+    fn test_build_query_params_list_my_subscriptions() {
+        let subscriptions = ListSubscriptions::create_with_mine()
+            .parse_part(PartSet::new().with(SubscriptionPart::Snippet))
+            .parse_for_channel_id(&["UC1", "UC2"])
+            .parse_order(SubscriptionOrder::Alphabetical)
+            .parse_max_results(50)
+            .build_query_parameters();
+        assert_eq!(
+            subscriptions.get_query_params(),
+            "part=snippet&mine=true&forChannelId=UC1,UC2&order=alphabetical&maxResults=50"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_subscribers() {
+        let recent = ListSubscriptions::create_with_my_recent_subscribers()
+            .parse_part(PartSet::new().with(SubscriptionPart::SubscriberSnippet))
+            .build_query_parameters();
+        assert_eq!(
+            recent.get_query_params(),
+            "part=subscriberSnippet&myRecentSubscribers=true"
+        );
+        let subscribers = ListSubscriptions::create_with_my_subscribers()
+            .parse_part(PartSet::new().with(SubscriptionPart::Id))
+            .build_query_parameters();
+        assert_eq!(subscribers.get_query_params(), "part=id&mySubscribers=true");
+    }
+    #[test]
+    fn test_build_query_params_channel_subscriptions() {
+        let subscriptions = ListSubscriptions::create_with_channel_id("UC123")
+            .parse_on_behalf_of_content_owner("owner", "UC456")
+            .build_query_parameters();
+        assert_eq!(
+            subscriptions.get_query_params(),
+            "part=snippet,contentDetails,id,subscriberSnippet&channelId=UC123\
+            &onBehalfOfContentOwner=owner&onBehalfOfContentOwnerChannel=UC456"
+        )
+    }
+}
@@ -0,0 +1,64 @@
+//! Canned JSON bodies shaped like the ones sent by the Youtube Api.
+
+use serde_json::{json, Value};
+
+/// A video resource with a snippet.
+pub fn video(id: &str, title: &str) -> Value {
+    json!({
+        "kind": "youtube#video",
+        "etag": format!("etag-{}", id),
+        "id": id,
+        "snippet": {
+            "publishedAt": "2021-04-01T10:00:00Z",
+            "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+            "title": title,
+            "description": format!("Description of {}", title),
+            "channelTitle": "Google Developers",
+            "tags": ["rust", "wasm"],
+            "categoryId": "28",
+            "liveBroadcastContent": "none",
+            "localized": {
+                "title": title,
+                "description": format!("Description of {}", title)
+            }
+        }
+    })
+}
+
+/// A list response of the given kind, `next_page_token` links to the following page.
+pub fn list(kind: &str, items: Vec<Value>, next_page_token: Option<&str>) -> String {
+    let mut list = json!({
+        "kind": kind,
+        "etag": "list-etag",
+        "pageInfo": {
+            "totalResults": items.len(),
+            "resultsPerPage": items.len()
+        },
+        "items": items
+    });
+    if let Some(token) = next_page_token {
+        list["nextPageToken"] = json!(token);
+    }
+    list.to_string()
+}
+
+/// A `videos.list` response.
+pub fn video_list(items: Vec<Value>, next_page_token: Option<&str>) -> String {
+    list("youtube#videoListResponse", items, next_page_token)
+}
+
+/// An error envelope, see https://developers.google.com/youtube/v3/docs/errors .
+pub fn error(code: u16, reason: &str, message: &str) -> String {
+    json!({
+        "error": {
+            "code": code,
+            "message": message,
+            "errors": [{
+                "message": message,
+                "domain": "youtube.api",
+                "reason": reason
+            }]
+        }
+    })
+    .to_string()
+}
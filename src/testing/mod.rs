@@ -0,0 +1,187 @@
+//! Offline helpers to test the endpoints and the applications built on them.
+//!
+//! [`MockTransport`] answers the requests with canned responses registered per resource and keeps
+//! every request it received so they can be inspected afterwards. [`fixtures`] provides the JSON
+//! bodies the Youtube Api sends back.
+
+use crate::query::QueryString;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+pub mod fixtures;
+
+/// Build a JSON response with the given status.
+pub fn json(status: u16, body: impl Into<String>) -> HttpResponse {
+    HttpResponse::new(status, body.into().into_bytes()).header("Content-Type", "application/json")
+}
+
+#[derive(Debug)]
+struct Route {
+    method: Method,
+    resource: String,
+    query: Vec<(String, String)>,
+    responses: VecDeque<HttpResponse>,
+}
+
+impl Route {
+    fn matches(&self, method: Method, path: &str, query: &[(String, String)]) -> bool {
+        self.method == method
+            && path.ends_with(&format!("/{}", self.resource))
+            && self.query.iter().all(|pair| query.contains(pair))
+    }
+
+    fn next_response(&mut self) -> HttpResponse {
+        if self.responses.len() > 1 {
+            self.responses.pop_front().unwrap_or_default()
+        } else {
+            self.responses.front().cloned().unwrap_or_default()
+        }
+    }
+}
+
+/// Transport serving canned responses and recording the requests.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    routes: RefCell<Vec<Route>>,
+    requests: RefCell<Vec<HttpRequest>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// Answer the requests made on a resource such as `videos` or `videos/rate`.
+    /// Registering several responses for the same route serves them in order, the last one is
+    /// then repeated.
+    pub fn respond(self, method: Method, resource: &str, response: HttpResponse) -> Self {
+        self.respond_when(method, resource, &[], response)
+    }
+
+    /// Answer the requests made on a resource only when the query contains every given pair,
+    /// handy to serve the pages of a list by `pageToken`. The pairs are compared decoded.
+    /// The most specific route wins when several of them match.
+    pub fn respond_when(
+        self,
+        method: Method,
+        resource: &str,
+        query: &[(&str, &str)],
+        response: HttpResponse,
+    ) -> Self {
+        let query: Vec<(String, String)> = query
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        {
+            let mut routes = self.routes.borrow_mut();
+            let existing = routes
+                .iter_mut()
+                .find(|r| r.method == method && r.resource == resource && r.query == query);
+            match existing {
+                Some(route) => route.responses.push_back(response),
+                None => routes.push(Route {
+                    method,
+                    resource: resource.to_string(),
+                    query,
+                    responses: vec![response].into(),
+                }),
+            }
+        }
+        self
+    }
+
+    /// Returns every request received so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.borrow().clone()
+    }
+
+    /// Returns the last request received.
+    pub fn last_request(&self) -> Option<HttpRequest> {
+        self.requests.borrow().last().cloned()
+    }
+}
+
+/// Split the url into its path and its decoded query pairs.
+fn split_url(url: &str) -> (&str, Vec<(String, String)>) {
+    match url.find('?') {
+        Some(index) => (
+            &url[..index],
+            QueryString::parse(&url[index + 1..]).pairs().to_vec(),
+        ),
+        None => (url, Vec::new()),
+    }
+}
+
+#[async_trait(?Send)]
+impl HttpTransport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let (path, query) = split_url(&request.url);
+        let response = {
+            let mut routes = self.routes.borrow_mut();
+            routes
+                .iter_mut()
+                .filter(|route| route.matches(request.method, path, &query))
+                .max_by_key(|route| route.query.len())
+                .map(Route::next_response)
+        };
+        let response = response.unwrap_or_else(|| {
+            json(
+                404,
+                fixtures::error(
+                    404,
+                    "notFound",
+                    &format!("No mock response for {} {}", request.method, path),
+                ),
+            )
+        });
+        self.requests.borrow_mut().push(request);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_serve_responses_in_order_then_repeat_the_last_one() {
+        let transport = MockTransport::new()
+            .respond(Method::Get, "videos", json(503, "first"))
+            .respond(Method::Get, "videos", json(200, "second"));
+        let request = HttpRequest::new(Method::Get, "https://host/youtube/v3/videos?id=1");
+        let statuses: Vec<u16> = (0..3)
+            .map(|_| block_on(transport.send(request.clone())).unwrap().status)
+            .collect();
+        assert_eq!(statuses, vec![503, 200, 200]);
+        assert_eq!(transport.requests().len(), 3);
+    }
+    #[test]
+    fn test_most_specific_route_wins() {
+        let transport = MockTransport::new()
+            .respond(Method::Get, "videos", json(200, "page 1"))
+            .respond_when(
+                Method::Get,
+                "videos",
+                &[("pageToken", "PAGE2")],
+                json(200, "page 2"),
+            );
+        let request =
+            HttpRequest::new(Method::Get, "https://host/youtube/v3/videos?pageToken=PAGE2");
+        let response = block_on(transport.send(request)).unwrap();
+        assert_eq!(response.text(), "page 2");
+    }
+    #[test]
+    fn test_unknown_route_is_not_found() {
+        let transport = MockTransport::new();
+        let request = HttpRequest::new(Method::Delete, "https://host/youtube/v3/videos?id=1");
+        let response = block_on(transport.send(request)).unwrap();
+        assert_eq!(response.status, 404);
+        assert_eq!(
+            transport.last_request().unwrap().url,
+            "https://host/youtube/v3/videos?id=1"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::resource::Thumbnails;
+use serde::{Deserialize, Serialize};
+
+/// Response of thumbnails.set https://developers.google.com/youtube/v3/docs/thumbnails/set#response
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ThumbnailSetResponse {
+    pub kind: String,
+    pub etag: String,
+    /// The thumbnails generated from the uploaded image, one set per video
+    pub items: Vec<Thumbnails>,
+}
+
+impl ThumbnailSetResponse {
+    /// Returns the thumbnails of the video.
+    pub fn thumbnails(&self) -> Option<&Thumbnails> {
+        self.items.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_deserialize_thumbnail_set_response() {
+        let body = r#"{
+            "kind": "youtube#thumbnailSetResponse",
+            "etag": "etag",
+            "items": [{
+                "default": {"url": "https://i.ytimg.com/vi/abc/default.jpg", "width": 120, "height": 90},
+                "high": {"url": "https://i.ytimg.com/vi/abc/hqdefault.jpg", "width": 480, "height": 360}
+            }]
+        }"#;
+        let response: ThumbnailSetResponse = serde_json::from_str(body).unwrap();
+        let thumbnails = response.thumbnails().unwrap();
+        assert_eq!(thumbnails.best().unwrap().width, Some(480));
+        assert!(thumbnails.medium.is_none());
+    }
+}
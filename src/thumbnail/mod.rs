@@ -0,0 +1,122 @@
+use crate::client::{Client, ClientError};
+use crate::response::build_response;
+use crate::thumbnail::data::ThumbnailSetResponse;
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use crate::upload::source::MediaSource;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::thumbnail::{data::*, query::*, ThumbnailEndPoint};
+}
+
+/// Largest image accepted by thumbnails.set.
+pub const MAX_THUMBNAIL_SIZE: u64 = 2 * 1024 * 1024;
+
+pub struct ThumbnailEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for ThumbnailEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        ThumbnailEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> ThumbnailEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.upload_url("thumbnails/set", "media");
+        ThumbnailEndPoint { client, url }
+    }
+
+    /// Uploads a custom thumbnail for a video, a JPEG or PNG image of at most 2MB, larger images
+    /// are rejected before being read.
+    /// The thumbnail has no metadata, so the image is sent alone as a media upload.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/thumbnails/set .
+    pub async fn set<M: MediaSource>(
+        &self,
+        query_search: &str,
+        mut image: M,
+    ) -> Result<ThumbnailSetResponse, ClientError> {
+        if image.size() > MAX_THUMBNAIL_SIZE {
+            return Err(ClientError::InvalidRequest(format!(
+                "the thumbnail is {} bytes, at most {} are allowed",
+                image.size(),
+                MAX_THUMBNAIL_SIZE
+            )));
+        }
+        let url = format!("{}&{}", &self.url, query_search);
+        let bytes = image.read(0, image.size() as usize).await?;
+        let request = HttpRequest::new(Method::Post, url)
+            .header("Content-Type", image.content_type())
+            .body(bytes);
+        build_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, json, MockTransport};
+    use crate::thumbnail::query::SetThumbnail;
+    use crate::upload::source::BytesSource;
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_thumbnail() {
+        let items = vec![json!({"default": {"url": "https://i.ytimg.com/vi/abc/default.jpg"}})];
+        let transport = MockTransport::new()
+            .respond_when(
+                Method::Post,
+                "thumbnails/set",
+                &[("videoId", "E6UTz_Doic8")],
+                json(
+                    200,
+                    fixtures::list("youtube#thumbnailSetResponse", items, None),
+                ),
+            )
+            .respond(
+                Method::Post,
+                "thumbnails/set",
+                json(403, fixtures::error(403, "forbidden", "Forbidden")),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let image = BytesSource::new(b"PNG".to_vec(), "image/png");
+        let query = SetThumbnail::new("E6UTz_Doic8").build_query_parameters();
+        let response = block_on(
+            client
+                .thumbnails()
+                .set(query.get_query_params(), image.clone()),
+        )
+        .unwrap();
+        assert!(response.thumbnails().unwrap().default_size.is_some());
+
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .starts_with("https://www.googleapis.com/upload/youtube/v3/thumbnails/set?"));
+        assert!(request.url.contains("uploadType=media&videoId=E6UTz_Doic8"));
+        assert_eq!(request.get_header("Content-Type"), Some("image/png"));
+        assert_eq!(request.body.unwrap(), b"PNG");
+
+        let query = SetThumbnail::new("other").build_query_parameters();
+        let result = block_on(client.thumbnails().set(query.get_query_params(), image));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 403));
+    }
+    #[test]
+    fn test_reject_large_thumbnail() {
+        let client = Client::with_transport("token", "key", MockTransport::new());
+        let image = BytesSource::new(vec![0; MAX_THUMBNAIL_SIZE as usize + 1], "image/jpeg");
+        let query = SetThumbnail::new("E6UTz_Doic8").build_query_parameters();
+        let result = block_on(client.thumbnails().set(query.get_query_params(), image));
+        assert!(matches!(result, Err(ClientError::InvalidRequest(_))));
+        assert!(client.transport().last_request().is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct SetThumbnail {
+    /// ID of the video the thumbnail is set for
+    video_id: String,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl SetThumbnail {
+    pub fn new(video_id: &str) -> SetThumbnail {
+        SetThumbnail::default().parse_video_id(video_id)
+    }
+    /// Parse video_id to its field
+    pub fn parse_video_id(mut self, video_id: &str) -> Self {
+        self.video_id = video_id.to_string();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the video id
+    pub fn video_id(&self) -> &str {
+        &self.video_id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("videoId", &self.video_id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_set_thumbnail() {
+        let thumbnail = SetThumbnail::new("E6UTz_Doic8")
+            .parse_on_behalf_of_content_owner("owner")
+            .build_query_parameters();
+        assert_eq!(
+            thumbnail.get_query_params(),
+            "videoId=E6UTz_Doic8&onBehalfOfContentOwner=owner"
+        )
+    }
+}
@@ -0,0 +1,178 @@
+//! Transport abstraction used by every endpoint so the crate is not tied to a single HTTP client.
+//!
+//! The endpoints only build [`HttpRequest`]s and read [`HttpResponse`]s, the actual sending is
+//! delegated to the [`HttpTransport`] the [`Client`](crate::client::Client) has been created with.
+
+use async_trait::async_trait;
+use core::fmt;
+use serde::Serialize;
+
+#[cfg(feature = "native")]
+pub mod native;
+pub mod retry;
+#[cfg(feature = "seed-fetch")]
+pub mod seed_fetch;
+
+#[cfg(feature = "native")]
+pub use native::ReqwestTransport;
+pub use retry::{RetryPolicy, RetryTransport, Timer};
+#[cfg(feature = "seed-fetch")]
+pub use seed_fetch::SeedTransport;
+
+/// Transport used by `Client::new`, Seed takes precedence when both features are enabled.
+#[cfg(feature = "seed-fetch")]
+pub type DefaultTransport = SeedTransport;
+
+/// Transport used by `Client::new`, Seed takes precedence when both features are enabled.
+#[cfg(all(feature = "native", not(feature = "seed-fetch")))]
+pub type DefaultTransport = ReqwestTransport;
+
+/// HTTP methods used by the Youtube Api.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Request handed over to the transport.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+    /// Must not be sent again by a retrying transport, such as an upload chunk
+    pub no_retry: bool,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        HttpRequest {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            no_retry: false,
+        }
+    }
+    /// Add a header to the request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+    /// Set the raw body of the request.
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+    /// Never send the request again, even when the method is idempotent.
+    pub fn no_retry(mut self) -> Self {
+        self.no_retry = true;
+        self
+    }
+    /// Serialize the body as JSON and set the content type.
+    pub fn json<B: Serialize + ?Sized>(self, body: &B) -> Result<Self, serde_json::Error> {
+        let body = serde_json::to_vec(body)?;
+        Ok(self.header("Content-Type", "application/json").body(body))
+    }
+    /// Returns the value of the first header matching the name, ignoring case.
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// Response returned by the transport, whatever its status code is.
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, body: Vec<u8>) -> Self {
+        HttpResponse {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+    /// Add a header to the response.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+    /// Returns the value of the first header matching the name, ignoring case.
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+    /// Is the status code 2xx ?
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+    /// Returns the body as text, invalid UTF-8 sequences are replaced.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Error raised when the request could not be sent or the response could not be read.
+#[derive(Debug, Clone)]
+pub struct TransportError {
+    message: String,
+}
+
+impl TransportError {
+    pub fn new(message: impl Into<String>) -> Self {
+        TransportError {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "transport error: {}", self.message)
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// Sends requests for the Client.
+///
+/// Implement it to use the endpoints with the HTTP client of your choice.
+#[async_trait(?Send)]
+pub trait HttpTransport {
+    /// Send the request and return the response, error statuses included.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError>;
+}
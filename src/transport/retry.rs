@@ -0,0 +1,467 @@
+//! Transport retrying the requests failing for a transient reason, with an exponential backoff.
+//!
+//! Enable it on a client with [`Client::with_retry`](crate::client::Client::with_retry).
+
+use crate::error::{ClientError, ErrorReason};
+use crate::response::deserialize_response;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Waits between two attempts, on the runtime the application runs on.
+#[async_trait(?Send)]
+pub trait Timer {
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Timer of the browser built on `setTimeout`.
+#[cfg(feature = "seed-fetch")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GlooTimer;
+
+#[cfg(feature = "seed-fetch")]
+#[async_trait(?Send)]
+impl Timer for GlooTimer {
+    async fn sleep(&self, duration: Duration) {
+        let millis = duration.as_millis().min(u32::MAX as u128) as u32;
+        gloo_timers::future::TimeoutFuture::new(millis).await
+    }
+}
+
+/// Timer of the Tokio runtime `reqwest` runs on.
+#[cfg(feature = "native")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioTimer;
+
+#[cfg(feature = "native")]
+#[async_trait(?Send)]
+impl Timer for TokioTimer {
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+/// Timer matching the `DefaultTransport`.
+#[cfg(feature = "seed-fetch")]
+pub type DefaultTimer = GlooTimer;
+
+/// Timer matching the `DefaultTransport`.
+#[cfg(all(feature = "native", not(feature = "seed-fetch")))]
+pub type DefaultTimer = TokioTimer;
+
+/// When and how long to wait before sending a request again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(32),
+            jitter: true,
+            retry_post: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+    /// Number of attempts including the first one, 1 disables the retries.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+    /// Delay before the first retry, doubled for each of the following ones.
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+    /// Upper bound of the delays. A response asking with `Retry-After` to wait longer is returned
+    /// without retrying.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+    /// Wait exactly the backoff instead of a random delay between its half and itself.
+    pub fn without_jitter(mut self) -> Self {
+        self.jitter = false;
+        self
+    }
+    /// Retry the POST requests too. They are not idempotent, a lost response then sent again
+    /// may create the resource twice.
+    pub fn with_post_retries(mut self) -> Self {
+        self.retry_post = true;
+        self
+    }
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Backoff before the retry following the failed `attempt`, starting at 0.
+    /// `random` is a uniform value used for the jitter.
+    fn backoff(&self, attempt: u32, random: u64) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        let delay = self
+            .initial_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        let millis = half.as_millis() as u64;
+        half + Duration::from_millis(random % (millis + 1))
+    }
+}
+
+/// Should the response be retried, and after how long when the server tells it ?
+fn retry_response(response: &HttpResponse) -> Option<Option<Duration>> {
+    let retry = match response.status {
+        429 | 500 | 503 => true,
+        status if status >= 400 => match deserialize_response::<Value>(response.text().as_str()) {
+            Err(ClientError::Youtube(e)) => {
+                e.has_reason(&ErrorReason::RateLimitExceeded)
+                    || e.has_reason(&ErrorReason::UserRateLimitExceeded)
+                    || e.has_reason(&ErrorReason::BackendError)
+            }
+            _ => false,
+        },
+        _ => false,
+    };
+    if retry {
+        Some(retry_after(response))
+    } else {
+        None
+    }
+}
+
+/// Delay of a `Retry-After` header in seconds. The HTTP-date form is not parsed, the backoff is
+/// used instead.
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    response
+        .get_header("Retry-After")
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Sends the requests through the inner transport, again after a delay when they fail with a
+/// network error, a 429, 500 or 503 status or a `rateLimitExceeded` or `backendError` reason.
+/// The last response is returned when every attempt failed.
+///
+/// Only the idempotent GET, PUT and DELETE requests are retried unless the policy allows POST.
+/// Requests marked with [`HttpRequest::no_retry`], such as the chunks of a resumable upload, are
+/// never retried here: the session must be queried before sending a chunk again, which
+/// `ResumableUpload::resume` does.
+#[derive(Debug)]
+pub struct RetryTransport<T, S> {
+    inner: T,
+    policy: RetryPolicy,
+    timer: S,
+    /// State of the xorshift generator used for the jitter
+    random: Cell<u64>,
+}
+
+impl<T, S> RetryTransport<T, S> {
+    pub fn new(inner: T, policy: RetryPolicy, timer: S) -> Self {
+        let seed = RandomState::new().build_hasher().finish();
+        RetryTransport {
+            inner,
+            policy,
+            timer,
+            // xorshift never leaves 0
+            random: Cell::new(seed | 1),
+        }
+    }
+    /// Get the transport the requests are sent through.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Can the request be sent again without side effect ?
+    fn is_retryable(&self, request: &HttpRequest) -> bool {
+        if request.no_retry {
+            return false;
+        }
+        match request.method {
+            Method::Get | Method::Put | Method::Delete => true,
+            Method::Post => self.policy.retry_post,
+        }
+    }
+
+    fn next_random(&self) -> u64 {
+        let mut x = self.random.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.random.set(x);
+        x
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: HttpTransport, S: Timer> HttpTransport for RetryTransport<T, S> {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        if !self.is_retryable(&request) {
+            return self.inner.send(request).await;
+        }
+        let mut attempt = 0;
+        loop {
+            let result = self.inner.send(request.clone()).await;
+            let server_delay = match &result {
+                Ok(response) => match retry_response(response) {
+                    Some(delay) => delay,
+                    None => return result,
+                },
+                Err(_) => None,
+            };
+            if attempt + 1 >= self.policy.max_attempts {
+                return result;
+            }
+            let delay = match server_delay {
+                Some(delay) if delay > self.policy.max_delay => return result,
+                Some(delay) => delay,
+                None => self.policy.backoff(attempt, self.next_random()),
+            };
+            self.timer.sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::testing::{fixtures, json, MockTransport};
+    use crate::transport::Method;
+    use crate::video::query::ListVideos;
+    use futures::executor::block_on;
+    use std::cell::RefCell;
+
+    const URL: &str = "https://www.googleapis.com/youtube/v3/videos?part=snippet";
+
+    /// Records the delays instead of waiting.
+    #[derive(Debug, Default)]
+    struct RecordingTimer {
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    #[async_trait(?Send)]
+    impl Timer for &RecordingTimer {
+        async fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_initial_delay(Duration::from_millis(100))
+            .without_jitter()
+    }
+
+    fn ok() -> HttpResponse {
+        json(
+            200,
+            fixtures::video_list(vec![fixtures::video("abc", "Title")], None),
+        )
+    }
+
+    fn millis(timer: &RecordingTimer) -> Vec<u128> {
+        timer
+            .sleeps
+            .borrow()
+            .iter()
+            .map(Duration::as_millis)
+            .collect()
+    }
+
+    #[test]
+    fn test_retry_server_errors_with_backoff() {
+        let transport = MockTransport::new()
+            .respond(Method::Get, "videos", json(503, ""))
+            .respond(Method::Get, "videos", json(500, ""))
+            .respond(Method::Get, "videos", json(429, ""))
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(retry.inner().requests().len(), 4);
+        assert_eq!(millis(&timer), vec![100, 200, 400]);
+    }
+    #[test]
+    fn test_retry_by_reason() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "videos",
+                json(
+                    403,
+                    fixtures::error(403, "rateLimitExceeded", "Rate Limit Exceeded"),
+                ),
+            )
+            .respond(Method::Get, "videos", ok())
+            .respond(
+                Method::Delete,
+                "videos",
+                json(403, fixtures::error(403, "quotaExceeded", "Quota Exceeded")),
+            );
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(response.status, 200);
+
+        let response = block_on(retry.send(HttpRequest::new(Method::Delete, URL))).unwrap();
+        assert_eq!(response.status, 403);
+        assert_eq!(retry.inner().requests().len(), 3);
+        assert_eq!(millis(&timer), vec![100]);
+    }
+    #[test]
+    fn test_retry_post_only_when_allowed() {
+        let transport = MockTransport::new()
+            .respond(Method::Post, "videos", json(503, ""))
+            .respond(Method::Post, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Post, URL))).unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(retry.inner().requests().len(), 1);
+
+        let transport = MockTransport::new()
+            .respond(Method::Post, "videos", json(503, ""))
+            .respond(Method::Post, "videos", ok());
+        let retry = RetryTransport::new(transport, policy().with_post_retries(), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Post, URL))).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(retry.inner().requests().len(), 2);
+    }
+    #[test]
+    fn test_never_retry_upload_chunks() {
+        let session =
+            "https://www.googleapis.com/upload/youtube/v3/videos?uploadType=resumable&upload_id=xyz";
+        let transport = MockTransport::new()
+            .respond(Method::Put, "videos", json(503, ""))
+            .respond(Method::Get, "videos", json(503, ""))
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        let chunk = HttpRequest::new(Method::Put, session).no_retry();
+        let response = block_on(retry.send(chunk)).unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(retry.inner().requests().len(), 1);
+        assert!(timer.sleeps.borrow().is_empty());
+        // Only the marker matters, not the shape of the url.
+        let query = format!("{}&q=upload_id=1", URL);
+        let response = block_on(retry.send(HttpRequest::new(Method::Get, query))).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(retry.inner().requests().len(), 3);
+    }
+    #[test]
+    fn test_honour_retry_after() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "videos",
+                json(429, "").header("Retry-After", "12"),
+            )
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(*timer.sleeps.borrow(), vec![Duration::from_secs(12)]);
+    }
+    #[test]
+    fn test_give_up_when_retry_after_exceeds_max_delay() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "videos",
+                json(503, "").header("Retry-After", "86400"),
+            )
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(response.status, 503);
+        assert!(timer.sleeps.borrow().is_empty());
+    }
+    #[test]
+    fn test_retry_after_date_falls_back_to_backoff() {
+        let transport = MockTransport::new()
+            .respond(
+                Method::Get,
+                "videos",
+                json(503, "").header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"),
+            )
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy(), &timer);
+        block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(millis(&timer), vec![100]);
+    }
+    #[test]
+    fn test_give_up_after_max_attempts() {
+        let transport = MockTransport::new().respond(
+            Method::Get,
+            "videos",
+            json(500, fixtures::error(500, "backendError", "Backend Error")),
+        );
+        let timer = RecordingTimer::default();
+        let retry = RetryTransport::new(transport, policy().with_max_attempts(3), &timer);
+        let response = block_on(retry.send(HttpRequest::new(Method::Get, URL))).unwrap();
+        assert_eq!(response.status, 500);
+        assert_eq!(retry.inner().requests().len(), 3);
+        assert_eq!(millis(&timer), vec![100, 200]);
+    }
+    #[test]
+    fn test_backoff_is_bounded() {
+        let policy = RetryPolicy::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::from_secs(10));
+        let retry = RetryTransport::new(MockTransport::new(), policy, NoTimer);
+        for attempt in 0..40 {
+            let backoff = retry.policy().backoff(attempt, retry.next_random());
+            let full = Duration::from_secs(1 << attempt.min(4)).min(Duration::from_secs(10));
+            assert!(backoff >= full / 2 && backoff <= full, "{:?}", backoff);
+        }
+    }
+    #[test]
+    fn test_client_with_retry() {
+        let transport = MockTransport::new()
+            .respond(Method::Get, "videos", json(503, ""))
+            .respond(Method::Get, "videos", ok());
+        let timer = RecordingTimer::default();
+        let client = Client::with_transport("token", "key", transport).with_retry(policy(), &timer);
+        let query = ListVideos::create_with_ids(&["abc"])
+            .unwrap()
+            .build_query_parameters();
+        let videos = block_on(client.video().list(query.get_query_params())).unwrap();
+        assert_eq!(videos.items[0].id, "abc");
+        assert_eq!(client.transport().inner().requests().len(), 2);
+    }
+
+    /// Timer of the backoff test, which never sleeps.
+    struct NoTimer;
+
+    #[async_trait(?Send)]
+    impl Timer for NoTimer {
+        async fn sleep(&self, _duration: Duration) {}
+    }
+}
@@ -0,0 +1,75 @@
+//! Transport built on `seed::fetch` for Seed applications running in the browser.
+
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+use async_trait::async_trait;
+use seed::fetch::{fetch, FetchError, Header, Method as FetchMethod, Request};
+use seed::prelude::{js_sys, JsCast, JsValue};
+
+/// Send the requests with the browser Fetch Api through Seed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SeedTransport;
+
+impl From<FetchError> for TransportError {
+    fn from(e: FetchError) -> Self {
+        TransportError::new(format!("{:?}", e))
+    }
+}
+
+impl From<Method> for FetchMethod {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => FetchMethod::Get,
+            Method::Post => FetchMethod::Post,
+            Method::Put => FetchMethod::Put,
+            Method::Delete => FetchMethod::Delete,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl HttpTransport for SeedTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let body: Option<JsValue> = request
+            .body
+            .as_ref()
+            .map(|bytes| js_sys::Uint8Array::from(bytes.as_slice()).into());
+
+        let mut fetch_request = Request::new(request.url.as_str()).method(request.method.into());
+        for (name, value) in &request.headers {
+            fetch_request = fetch_request.header(Header::custom(name.as_str(), value.as_str()));
+        }
+        if let Some(body) = &body {
+            fetch_request = fetch_request.body(body);
+        }
+
+        let response = fetch(fetch_request).await?;
+        let status = response.status().code;
+        let headers = read_headers(response.raw_response())?;
+        let body = response.bytes().await?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Collect the headers exposed to the browser.
+fn read_headers(
+    response: &seed::prelude::web_sys::Response,
+) -> Result<Vec<(String, String)>, TransportError> {
+    let mut headers = Vec::new();
+    let entries = js_sys::try_iter(response.headers().as_ref())
+        .map_err(|e| TransportError::new(format!("{:?}", e)))?;
+    if let Some(entries) = entries {
+        for entry in entries {
+            let entry = entry.map_err(|e| TransportError::new(format!("{:?}", e)))?;
+            let pair: js_sys::Array = entry.unchecked_into();
+            if let (Some(name), Some(value)) = (pair.get(0).as_string(), pair.get(1).as_string()) {
+                headers.push((name, value));
+            }
+        }
+    }
+    Ok(headers)
+}
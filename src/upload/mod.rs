@@ -0,0 +1,14 @@
+//! Media uploads, see https://developers.google.com/youtube/v3/guides/using_resumable_upload_protocol .
+//!
+//! Small media can be sent in one request along with the metadata of their resource with a
+//! [`MultipartRelated`](multipart::MultipartRelated) body. Larger ones are read from a [`MediaSource`](source::MediaSource), a `Blob` or a `File` in the
+//! browser, a file or any reader on native, and sent in chunks by a
+//! [`ResumableUpload`](resumable::ResumableUpload) which can be resumed after an interruption.
+
+pub mod multipart;
+pub mod resumable;
+pub mod source;
+
+pub mod prelude {
+    pub use crate::upload::{multipart::*, resumable::*, source::*};
+}
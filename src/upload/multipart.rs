@@ -0,0 +1,118 @@
+//! Multipart upload protocol: the metadata and a small media sent together in one
+//! `multipart/related` request, see https://developers.google.com/youtube/v3/guides/uploading_a_video .
+
+use crate::transport::{HttpRequest, Method};
+use serde::Serialize;
+
+/// Separates the parts of the body unless it appears in one of them.
+const BOUNDARY: &str = "youtube_api_rs_multipart_boundary";
+
+/// Body made of the JSON metadata of a resource followed by its media.
+#[derive(Debug, Clone, Default)]
+pub struct MultipartRelated {
+    parts: Vec<(String, Vec<u8>)>,
+}
+
+impl MultipartRelated {
+    pub fn new() -> Self {
+        MultipartRelated::default()
+    }
+
+    /// Add the metadata part, it must come before the media.
+    pub fn metadata<B: Serialize + ?Sized>(
+        mut self,
+        metadata: &B,
+    ) -> Result<Self, serde_json::Error> {
+        let json = serde_json::to_vec(metadata)?;
+        self.parts
+            .push(("application/json; charset=UTF-8".to_string(), json));
+        Ok(self)
+    }
+
+    /// Add the media part.
+    pub fn media(mut self, content_type: &str, media: &[u8]) -> Self {
+        self.parts.push((content_type.to_string(), media.to_vec()));
+        self
+    }
+
+    /// Pick a boundary found in none of the parts.
+    fn boundary(&self) -> String {
+        let mut boundary = BOUNDARY.to_string();
+        let mut attempt = 0;
+        while self
+            .parts
+            .iter()
+            .any(|(_, body)| contains(body, boundary.as_bytes()))
+        {
+            attempt += 1;
+            boundary = format!("{}_{}", BOUNDARY, attempt);
+        }
+        boundary
+    }
+
+    /// Build the request with its `multipart/related` content type and body.
+    pub fn into_request(self, method: Method, url: impl Into<String>) -> HttpRequest {
+        let boundary = self.boundary();
+        let mut body = Vec::new();
+        for (content_type, part) in &self.parts {
+            body.extend_from_slice(
+                format!("--{}\r\nContent-Type: {}\r\n\r\n", boundary, content_type).as_bytes(),
+            );
+            body.extend_from_slice(part);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--", boundary).as_bytes());
+        HttpRequest::new(method, url)
+            .header(
+                "Content-Type",
+                &format!("multipart/related; boundary={}", boundary),
+            )
+            .body(body)
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_multipart_body() {
+        let request = MultipartRelated::new()
+            .metadata(&json!({"snippet": {"title": "Title"}}))
+            .unwrap()
+            .media("image/png", b"PNG")
+            .into_request(Method::Post, "https://example.com/upload");
+        assert_eq!(
+            request.get_header("Content-Type"),
+            Some("multipart/related; boundary=youtube_api_rs_multipart_boundary")
+        );
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+        assert_eq!(
+            body,
+            "--youtube_api_rs_multipart_boundary\r\n\
+            Content-Type: application/json; charset=UTF-8\r\n\r\n\
+            {\"snippet\":{\"title\":\"Title\"}}\r\n\
+            --youtube_api_rs_multipart_boundary\r\n\
+            Content-Type: image/png\r\n\r\n\
+            PNG\r\n\
+            --youtube_api_rs_multipart_boundary--"
+        );
+    }
+    #[test]
+    fn test_boundary_not_in_media() {
+        let request = MultipartRelated::new()
+            .media("text/plain", b"--youtube_api_rs_multipart_boundary--")
+            .into_request(Method::Post, "https://example.com/upload");
+        assert_eq!(
+            request.get_header("Content-Type"),
+            Some("multipart/related; boundary=youtube_api_rs_multipart_boundary_1")
+        );
+    }
+}
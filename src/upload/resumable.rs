@@ -0,0 +1,442 @@
+//! Resumable upload protocol: the metadata opens an upload session, the media is then sent by
+//! chunks and the session can be queried to resume after an interruption.
+
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::response::{build_error, deserialize_response};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method};
+use crate::upload::source::MediaSource;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Every chunk but the last one must be a multiple of this size.
+pub const CHUNK_GRANULARITY: usize = 256 * 1024;
+/// Chunk size used unless another one is set, 8 MiB.
+pub const DEFAULT_CHUNK_SIZE: usize = 32 * CHUNK_GRANULARITY;
+
+/// Status code sent while the upload is not complete.
+const RESUME_INCOMPLETE: u16 = 308;
+
+/// How much of the media the server has received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub uploaded: u64,
+    pub total: u64,
+}
+
+impl UploadProgress {
+    /// Uploaded part between 0 and 1.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.uploaded as f64 / self.total as f64
+        }
+    }
+}
+
+/// Status of the upload session.
+#[derive(Debug)]
+pub enum UploadStatus<R> {
+    /// The server has received the first `uploaded` bytes of the media.
+    Incomplete { uploaded: u64 },
+    /// The media is uploaded and the resource created.
+    Complete(R),
+}
+
+/// Upload session of a media creating a resource `R`, such as a `YoutubeVideo`.
+pub struct ResumableUpload<'a, T, M, R> {
+    client: &'a Client<T>,
+    session_uri: String,
+    media: M,
+    uploaded: u64,
+    chunk_size: usize,
+    on_progress: Option<Box<dyn FnMut(UploadProgress) + 'a>>,
+    resource: PhantomData<R>,
+}
+
+impl<'a, T, M, R> fmt::Debug for ResumableUpload<'a, T, M, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResumableUpload")
+            .field("session_uri", &self.session_uri)
+            .field("uploaded", &self.uploaded)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+impl<'a, T, M, R> ResumableUpload<'a, T, M, R>
+where
+    T: HttpTransport,
+    M: MediaSource,
+    R: DeserializeOwned,
+{
+    /// Open the session by sending the metadata of the resource to the upload url.
+    pub(crate) async fn initiate<B: Serialize + ?Sized>(
+        client: &'a Client<T>,
+        method: Method,
+        url: String,
+        metadata: &B,
+        media: M,
+    ) -> Result<ResumableUpload<'a, T, M, R>, ClientError> {
+        let request = HttpRequest::new(method, url)
+            .json(metadata).map_err(invalid_body)?
+            .header("X-Upload-Content-Length", &media.size().to_string())
+            .header("X-Upload-Content-Type", media.content_type());
+        let response = client.transport().send(request).await?;
+        if !response.is_success() {
+            return build_error(&response);
+        }
+        let session_uri = response
+            .get_header("Location")
+            .ok_or_else(|| ClientError::Status {
+                status: response.status,
+                body: "missing upload session uri".to_string(),
+            })?;
+        Ok(ResumableUpload::from_session(client, session_uri, media))
+    }
+
+    /// Continue a session opened earlier, call [`resume`](Self::resume) to find out where the
+    /// upload stopped.
+    pub fn from_session(client: &'a Client<T>, session_uri: &str, media: M) -> Self {
+        ResumableUpload {
+            client,
+            session_uri: session_uri.to_string(),
+            media,
+            uploaded: 0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            on_progress: None,
+            resource: PhantomData,
+        }
+    }
+
+    /// Size of the chunks, rounded up to a multiple of 256 KiB.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        let chunks = chunk_size
+            .div_ceil(CHUNK_GRANULARITY)
+            .clamp(1, usize::MAX / CHUNK_GRANULARITY);
+        self.chunk_size = chunks * CHUNK_GRANULARITY;
+        self
+    }
+
+    /// Called each time the server acknowledges a chunk.
+    pub fn on_progress(mut self, on_progress: impl FnMut(UploadProgress) + 'a) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Uri of the session, keep it to resume the upload later with
+    /// [`from_session`](Self::from_session).
+    pub fn session_uri(&self) -> &str {
+        &self.session_uri
+    }
+
+    pub fn progress(&self) -> UploadProgress {
+        UploadProgress {
+            uploaded: self.uploaded,
+            total: self.media.size(),
+        }
+    }
+
+    /// Send the remaining chunks and return the created resource.
+    pub async fn upload(&mut self) -> Result<R, ClientError> {
+        let total = self.media.size();
+        let mut restarted = false;
+        loop {
+            let start = self.uploaded;
+            let chunk = self.media.read(start, self.chunk_size).await?;
+            let range = if chunk.is_empty() {
+                format!("bytes */{}", total)
+            } else {
+                format!(
+                    "bytes {}-{}/{}",
+                    start,
+                    start + chunk.len() as u64 - 1,
+                    total
+                )
+            };
+            let request = HttpRequest::new(Method::Put, self.session_uri.as_str())
+                .header("Content-Range", &range)
+                .body(chunk)
+                .no_retry();
+            let response = self.client.transport().send(request).await?;
+            match self.read_status(&response)? {
+                UploadStatus::Complete(resource) => return Ok(resource),
+                UploadStatus::Incomplete { uploaded } if uploaded > start => {}
+                // Without `Range` the server kept none of the media, start over once from byte 0.
+                UploadStatus::Incomplete { .. }
+                    if start > 0 && !restarted && response.get_header("Range").is_none() =>
+                {
+                    restarted = true;
+                }
+                // Nothing was stored, resend the chunk with `resume` rather than loop forever.
+                UploadStatus::Incomplete { .. } => {
+                    return Err(ClientError::Status {
+                        status: response.status,
+                        body: "upload made no progress".to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Ask the server how much of the media it has received.
+    pub async fn status(&mut self) -> Result<UploadStatus<R>, ClientError> {
+        let request = HttpRequest::new(Method::Put, self.session_uri.as_str())
+            .header("Content-Range", &format!("bytes */{}", self.media.size()))
+            .body(Vec::new());
+        let response = self.client.transport().send(request).await?;
+        self.read_status(&response)
+    }
+
+    /// Continue an interrupted upload from where the server stopped receiving it.
+    pub async fn resume(&mut self) -> Result<R, ClientError> {
+        match self.status().await? {
+            UploadStatus::Complete(resource) => Ok(resource),
+            UploadStatus::Incomplete { .. } => self.upload().await,
+        }
+    }
+
+    fn read_status(&mut self, response: &HttpResponse) -> Result<UploadStatus<R>, ClientError> {
+        let status = match response.status {
+            200 | 201 => {
+                let resource = deserialize_response(response.text().as_str())?;
+                self.uploaded = self.media.size();
+                UploadStatus::Complete(resource)
+            }
+            // No `Range` means that nothing was received yet.
+            RESUME_INCOMPLETE => {
+                self.uploaded = response
+                    .get_header("Range")
+                    .map(parse_range)
+                    .transpose()?
+                    .unwrap_or(0);
+                UploadStatus::Incomplete {
+                    uploaded: self.uploaded,
+                }
+            }
+            _ => return build_error(response),
+        };
+        let progress = self.progress();
+        if let Some(on_progress) = self.on_progress.as_mut() {
+            on_progress(progress);
+        }
+        Ok(status)
+    }
+}
+
+/// Number of bytes received from a `Range: bytes=0-N` header.
+fn parse_range(range: &str) -> Result<u64, ClientError> {
+    range
+        .trim()
+        .strip_prefix("bytes=0-")
+        .and_then(|last| last.parse::<u64>().ok())
+        .map(|last| last + 1)
+        .ok_or_else(|| ClientError::Status {
+            status: RESUME_INCOMPLETE,
+            body: format!("invalid range {}", range),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, json, MockTransport};
+    use crate::upload::source::BytesSource;
+    use crate::video::data::YoutubeVideo;
+    use futures::executor::block_on;
+    use serde_json::json as value;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const SESSION: &str =
+        "https://www.googleapis.com/upload/youtube/v3/videos?uploadType=resumable&upload_id=xyz";
+    const SIZE: usize = 2 * CHUNK_GRANULARITY + 100;
+
+    fn incomplete(last: usize) -> HttpResponse {
+        HttpResponse::new(RESUME_INCOMPLETE, Vec::new())
+            .header("Range", &format!("bytes=0-{}", last))
+    }
+
+    fn client(transport: MockTransport) -> Client<MockTransport> {
+        let transport = transport.respond(
+            Method::Post,
+            "videos",
+            HttpResponse::new(200, Vec::new()).header("Location", SESSION),
+        );
+        Client::with_transport("token", "key", transport)
+    }
+
+    fn initiate(
+        client: &Client<MockTransport>,
+    ) -> ResumableUpload<'_, MockTransport, BytesSource, YoutubeVideo> {
+        let url = client.upload_url("videos", "resumable");
+        let media = BytesSource::new(vec![7; SIZE], "video/mp4");
+        block_on(ResumableUpload::initiate(
+            client,
+            Method::Post,
+            url,
+            &value!({"snippet": {"title": "Title"}}),
+            media,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upload_by_chunks() {
+        let transport = MockTransport::new()
+            .respond(Method::Put, "videos", incomplete(CHUNK_GRANULARITY - 1))
+            .respond(Method::Put, "videos", incomplete(2 * CHUNK_GRANULARITY - 1))
+            .respond(
+                Method::Put,
+                "videos",
+                json(200, fixtures::video("abc", "Title").to_string()),
+            );
+        let client = client(transport);
+        let progress = Rc::new(RefCell::new(Vec::new()));
+        let recorded = progress.clone();
+        let mut upload = initiate(&client)
+            .chunk_size(1)
+            .on_progress(move |p| recorded.borrow_mut().push(p.uploaded));
+        assert_eq!(upload.session_uri(), SESSION);
+
+        let video = block_on(upload.upload()).unwrap();
+        assert_eq!(video.id, "abc");
+        let granularity = CHUNK_GRANULARITY as u64;
+        assert_eq!(
+            *progress.borrow(),
+            vec![granularity, 2 * granularity, SIZE as u64]
+        );
+
+        let requests = client.transport().requests();
+        assert_eq!(requests.len(), 4);
+        let init = &requests[0];
+        assert!(init.url.contains("uploadType=resumable"));
+        assert_eq!(init.get_header("X-Upload-Content-Length"), Some("524388"));
+        assert_eq!(init.get_header("X-Upload-Content-Type"), Some("video/mp4"));
+        assert_eq!(requests[1].url, SESSION);
+        assert!(requests[1].no_retry && !init.no_retry);
+        assert_eq!(
+            requests[1].get_header("Content-Range"),
+            Some("bytes 0-262143/524388")
+        );
+        assert_eq!(
+            requests[3].get_header("Content-Range"),
+            Some("bytes 524288-524387/524388")
+        );
+        assert_eq!(requests[3].body.as_ref().unwrap().len(), 100);
+    }
+    #[test]
+    fn test_resume_after_interruption() {
+        let transport = MockTransport::new()
+            .respond(Method::Put, "videos", incomplete(CHUNK_GRANULARITY - 1))
+            .respond(
+                Method::Put,
+                "videos",
+                json(503, fixtures::error(503, "backendError", "Backend Error")),
+            )
+            .respond(Method::Put, "videos", incomplete(CHUNK_GRANULARITY - 1))
+            .respond(Method::Put, "videos", incomplete(2 * CHUNK_GRANULARITY - 1))
+            .respond(
+                Method::Put,
+                "videos",
+                json(200, fixtures::video("abc", "Title").to_string()),
+            );
+        let client = client(transport);
+        let mut upload = initiate(&client).chunk_size(CHUNK_GRANULARITY);
+
+        let result = block_on(upload.upload());
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 503));
+        assert_eq!(upload.progress().uploaded, CHUNK_GRANULARITY as u64);
+
+        let video = block_on(upload.resume()).unwrap();
+        assert_eq!(video.id, "abc");
+        assert!((upload.progress().fraction() - 1.0).abs() < f64::EPSILON);
+        let requests = client.transport().requests();
+        assert_eq!(
+            requests[3].get_header("Content-Range"),
+            Some("bytes */524388")
+        );
+        assert!(requests[3].body.as_ref().unwrap().is_empty());
+        assert_eq!(
+            requests[4].get_header("Content-Range"),
+            Some("bytes 262144-524287/524388")
+        );
+    }
+    #[test]
+    fn test_status_of_new_session() {
+        let transport = MockTransport::new().respond(
+            Method::Put,
+            "videos",
+            HttpResponse::new(RESUME_INCOMPLETE, Vec::new()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let media = BytesSource::new(vec![0; 10], "video/mp4");
+        let mut upload: ResumableUpload<'_, _, _, YoutubeVideo> =
+            ResumableUpload::from_session(&client, SESSION, media);
+        let status = block_on(upload.status()).unwrap();
+        assert!(matches!(status, UploadStatus::Incomplete { uploaded: 0 }));
+        let result = block_on(upload.upload());
+        assert!(matches!(
+            result,
+            Err(ClientError::Status { status: 308, .. })
+        ));
+    }
+    #[test]
+    fn test_restart_when_server_lost_the_media() {
+        let transport = MockTransport::new()
+            .respond(Method::Put, "videos", incomplete(CHUNK_GRANULARITY - 1))
+            .respond(
+                Method::Put,
+                "videos",
+                HttpResponse::new(RESUME_INCOMPLETE, Vec::new()),
+            )
+            .respond(Method::Put, "videos", incomplete(2 * CHUNK_GRANULARITY - 1))
+            .respond(
+                Method::Put,
+                "videos",
+                json(200, fixtures::video("abc", "Title").to_string()),
+            );
+        let client = client(transport);
+        let mut upload = initiate(&client).chunk_size(CHUNK_GRANULARITY);
+        let video = block_on(upload.upload()).unwrap();
+        assert_eq!(video.id, "abc");
+        let requests = client.transport().requests();
+        assert_eq!(
+            requests[3].get_header("Content-Range"),
+            Some("bytes 0-262143/524388")
+        );
+    }
+    #[test]
+    fn test_initiate_error() {
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "videos",
+            json(403, fixtures::error(403, "forbidden", "Forbidden")),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let media = BytesSource::new(vec![0; 10], "video/mp4");
+        let result: Result<ResumableUpload<'_, _, _, YoutubeVideo>, _> =
+            block_on(ResumableUpload::initiate(
+                &client,
+                Method::Post,
+                SESSION.to_string(),
+                &value!({}),
+                media,
+            ));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 403));
+    }
+    #[test]
+    fn test_chunk_size_is_rounded() {
+        let client = Client::with_transport("token", "key", MockTransport::new());
+        let media = BytesSource::new(Vec::new(), "video/mp4");
+        let upload: ResumableUpload<'_, _, _, YoutubeVideo> =
+            ResumableUpload::from_session(&client, SESSION, media)
+                .chunk_size(CHUNK_GRANULARITY + 1);
+        assert_eq!(upload.chunk_size, 2 * CHUNK_GRANULARITY);
+        let upload = upload.chunk_size(usize::MAX);
+        assert_eq!(upload.chunk_size % CHUNK_GRANULARITY, 0);
+    }
+}
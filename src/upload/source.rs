@@ -0,0 +1,185 @@
+//! Sources the media of an upload is read from.
+
+use crate::transport::TransportError;
+use async_trait::async_trait;
+use std::io::{Read, Seek, SeekFrom};
+
+/// Content type sent when the media does not tell its own.
+pub const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Media read by chunks while uploading.
+///
+/// Implement it to upload from a source this crate does not provide.
+#[async_trait(?Send)]
+pub trait MediaSource {
+    /// Total size of the media in bytes.
+    fn size(&self) -> u64;
+    /// MIME type of the media, such as `video/mp4`.
+    fn content_type(&self) -> &str;
+    /// Read at most `len` bytes starting at `offset`, less are returned only at the end of the
+    /// media.
+    async fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, TransportError>;
+}
+
+/// Media already loaded in memory.
+#[derive(Debug, Clone)]
+pub struct BytesSource {
+    bytes: Vec<u8>,
+    content_type: String,
+}
+
+impl BytesSource {
+    pub fn new(bytes: Vec<u8>, content_type: &str) -> Self {
+        BytesSource {
+            bytes,
+            content_type: content_type.to_string(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl MediaSource for BytesSource {
+    fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+    fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    async fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, TransportError> {
+        let start = (offset as usize).min(self.bytes.len());
+        let end = start.saturating_add(len).min(self.bytes.len());
+        Ok(self.bytes[start..end].to_vec())
+    }
+}
+
+/// Media read from a seekable reader such as a `std::fs::File` or a `std::io::Cursor`.
+#[derive(Debug)]
+pub struct ReaderSource<R> {
+    reader: R,
+    start: u64,
+    size: u64,
+    content_type: String,
+}
+
+impl<R: Read + Seek> ReaderSource<R> {
+    /// The media starts at the current position of the reader and ends with it.
+    pub fn new(mut reader: R, content_type: &str) -> std::io::Result<Self> {
+        let start = reader.stream_position()?;
+        let end = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(start))?;
+        Ok(ReaderSource {
+            reader,
+            start,
+            size: end - start,
+            content_type: content_type.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "native")]
+impl ReaderSource<std::fs::File> {
+    /// Open the file to upload.
+    pub fn open(path: impl AsRef<std::path::Path>, content_type: &str) -> std::io::Result<Self> {
+        ReaderSource::new(std::fs::File::open(path)?, content_type)
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: Read + Seek> MediaSource for ReaderSource<R> {
+    fn size(&self) -> u64 {
+        self.size
+    }
+    fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    async fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, TransportError> {
+        let io_error = |e: std::io::Error| TransportError::new(format!("cannot read media: {}", e));
+        let len = self.size.saturating_sub(offset).min(len as u64);
+        self.reader
+            .seek(SeekFrom::Start(self.start + offset))
+            .map_err(io_error)?;
+        let mut buffer = Vec::with_capacity(len as usize);
+        (&mut self.reader)
+            .take(len)
+            .read_to_end(&mut buffer)
+            .map_err(io_error)?;
+        Ok(buffer)
+    }
+}
+
+/// Media picked by the user in the browser, a `File` is a `Blob` too.
+#[cfg(feature = "seed-fetch")]
+#[derive(Debug, Clone)]
+pub struct BlobSource {
+    blob: seed::prelude::web_sys::Blob,
+    content_type: String,
+}
+
+#[cfg(feature = "seed-fetch")]
+impl BlobSource {
+    /// The content type is the type of the blob, or `application/octet-stream` when unknown.
+    pub fn new(blob: seed::prelude::web_sys::Blob) -> Self {
+        let content_type = match blob.type_() {
+            content_type if content_type.is_empty() => DEFAULT_CONTENT_TYPE.to_string(),
+            content_type => content_type,
+        };
+        BlobSource { blob, content_type }
+    }
+
+    /// Upload a file from an `<input type="file">`.
+    pub fn from_file(file: seed::prelude::web_sys::File) -> Self {
+        BlobSource::new(file.into())
+    }
+}
+
+#[cfg(feature = "seed-fetch")]
+#[async_trait(?Send)]
+impl MediaSource for BlobSource {
+    fn size(&self) -> u64 {
+        self.blob.size() as u64
+    }
+    fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    async fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, TransportError> {
+        use seed::prelude::js_sys::Uint8Array;
+        use seed::wasm_bindgen_futures::JsFuture;
+
+        let js_error = |e| TransportError::new(format!("cannot read media: {:?}", e));
+        let end = self.size().min(offset.saturating_add(len as u64));
+        let chunk = self
+            .blob
+            .slice_with_f64_and_f64(offset as f64, end as f64)
+            .map_err(js_error)?;
+        let buffer = JsFuture::from(chunk.array_buffer())
+            .await
+            .map_err(js_error)?;
+        Ok(Uint8Array::new(&buffer).to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_bytes_source_reads_chunks() {
+        let mut source = BytesSource::new(b"0123456789".to_vec(), "video/mp4");
+        assert_eq!(source.size(), 10);
+        assert_eq!(block_on(source.read(4, 4)).unwrap(), b"4567");
+        assert_eq!(block_on(source.read(8, 4)).unwrap(), b"89");
+        assert!(block_on(source.read(12, 4)).unwrap().is_empty());
+    }
+    #[test]
+    fn test_reader_source_starts_at_reader_position() {
+        let mut cursor = Cursor::new(b"header0123456789".to_vec());
+        cursor.set_position(6);
+        let mut source = ReaderSource::new(cursor, "video/mp4").unwrap();
+        assert_eq!(source.size(), 10);
+        assert_eq!(source.content_type(), "video/mp4");
+        assert_eq!(block_on(source.read(0, 4)).unwrap(), b"0123");
+        assert_eq!(block_on(source.read(6, 8)).unwrap(), b"6789");
+    }
+}
@@ -1,33 +1,524 @@
+pub use crate::resource::Localization;
+use crate::resource::{option_u64, PrivacyStatus, Thumbnails};
 use serde::{Deserialize,Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Former name of [`Localization`].
+#[deprecated(note = "renamed to `Localization`")]
+pub type Location = Localization;
 
 /// The video data model https://developers.google.com/youtube/v3/docs/videos#resource
 #[derive(Debug, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct YoutubeVideo {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub etag: String,
+    /// Empty when inserting a video
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snippet: Option<VideoSnippet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_details: Option<VideoContentDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VideoStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<VideoStatistics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player: Option<VideoPlayer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_details: Option<VideoTopicDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_details: Option<VideoRecordingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_details: Option<VideoFileDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_details: Option<VideoProcessingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<VideoSuggestions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_streaming_details: Option<VideoLiveStreamingDetails>,
+    /// Translations of the title and description, keyed by BCP-47 language code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localizations: Option<BTreeMap<String, Localization>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoSnippet {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub published_at: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub channel_id: String,
     pub title: String,
+    #[serde(default)]
     pub description: String,
+    #[serde(skip_serializing)]
+    pub thumbnails: Option<Thumbnails>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub channel_title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub category_id: String,
+    #[serde(skip_serializing)]
     pub live_broadcast_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_language: Option<String>,
-    pub localized: Location,
+    #[serde(default, skip_serializing)]
+    pub localized: Localization,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_audio_language: Option<String>,
 }
 
+/// Information about the video content https://developers.google.com/youtube/v3/docs/videos#contentDetails
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoContentDetails {
+    /// ISO 8601 duration, e.g. `PT15M33S`.
+    #[serde(default)]
+    pub duration: String,
+    /// `2d` or `3d`.
+    #[serde(default)]
+    pub dimension: String,
+    /// `hd` or `sd`.
+    #[serde(default)]
+    pub definition: String,
+    /// `true` when captions are available, the Api sends it as a string.
+    #[serde(default)]
+    pub caption: String,
+    #[serde(default)]
+    pub licensed_content: bool,
+    pub region_restriction: Option<RegionRestriction>,
+    pub content_rating: Option<ContentRating>,
+    /// `360` or `rectangular`.
+    #[serde(default)]
+    pub projection: String,
+    pub has_custom_thumbnail: Option<bool>,
+}
+
+/// Countries where the video is viewable, only one of the lists is set.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionRestriction {
+    pub allowed: Option<Vec<String>>,
+    pub blocked: Option<Vec<String>>,
+}
+
+/// Ratings the video received under the rating schemes of the different countries.
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentRating {
+    /// `ytAgeRestricted` when Youtube flagged the video as age-restricted.
+    pub yt_rating: Option<String>,
+    pub mpaa_rating: Option<String>,
+    pub tvpg_rating: Option<String>,
+    pub bbfc_rating: Option<String>,
+    pub fsk_rating: Option<String>,
+    pub cbfc_rating: Option<String>,
+    pub acb_rating: Option<String>,
+    /// Every other rating scheme, keyed by its Api name such as `eirinRating`.
+    #[serde(flatten)]
+    pub other_ratings: BTreeMap<String, Value>,
+}
+
+/// Upload, processing and privacy status https://developers.google.com/youtube/v3/docs/videos#status
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejection_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_status: Option<PrivacyStatus>,
+    /// Scheduled publication date, only for private videos.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<String>,
+    /// `youtube` or `creativeCommon`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeddable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_stats_viewable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub made_for_kids: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_declared_made_for_kids: Option<bool>,
+}
+
+/// Statistics about the video https://developers.google.com/youtube/v3/docs/videos#statistics
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStatistics {
+    #[serde(default, with = "option_u64")]
+    pub view_count: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub like_count: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub dislike_count: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub favorite_count: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub comment_count: Option<u64>,
+}
+
+/// Embedded player https://developers.google.com/youtube/v3/docs/videos#player
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoPlayer {
+    /// `<iframe>` tag embedding the player.
+    pub embed_html: String,
+    #[serde(default, with = "option_u64")]
+    pub embed_height: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub embed_width: Option<u64>,
+}
+
+/// Topics associated with the video https://developers.google.com/youtube/v3/docs/videos#topicDetails
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoTopicDetails {
+    pub topic_ids: Option<Vec<String>>,
+    pub relevant_topic_ids: Option<Vec<String>>,
+    /// Wikipedia URLs describing the content of the video.
+    pub topic_categories: Option<Vec<String>>,
+}
+
+/// Where and when the video was recorded https://developers.google.com/youtube/v3/docs/videos#recordingDetails
 #[derive(Debug, Deserialize, Serialize, Default)]
-pub struct Location {
-    title: String,
-    description: String,
+#[serde(rename_all = "camelCase")]
+pub struct VideoRecordingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<GeoPoint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+}
+
+/// Information about the uploaded file, only returned to the owner
+/// https://developers.google.com/youtube/v3/docs/videos#fileDetails
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoFileDetails {
+    pub file_name: Option<String>,
+    #[serde(default, with = "option_u64")]
+    pub file_size: Option<u64>,
+    /// `video`, `audio`, `image`, `archive`, `document`, `project` or `other`.
+    pub file_type: Option<String>,
+    pub container: Option<String>,
+    pub video_streams: Option<Vec<VideoStream>>,
+    pub audio_streams: Option<Vec<AudioStream>>,
+    #[serde(default, with = "option_u64")]
+    pub duration_ms: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub bitrate_bps: Option<u64>,
+    pub creation_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStream {
+    pub width_pixels: Option<u32>,
+    pub height_pixels: Option<u32>,
+    pub frame_rate_fps: Option<f64>,
+    pub aspect_ratio: Option<f64>,
+    pub codec: Option<String>,
+    #[serde(default, with = "option_u64")]
+    pub bitrate_bps: Option<u64>,
+    pub rotation: Option<String>,
+    pub vendor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStream {
+    pub channel_count: Option<u32>,
+    pub codec: Option<String>,
+    #[serde(default, with = "option_u64")]
+    pub bitrate_bps: Option<u64>,
+    pub vendor: Option<String>,
+}
+
+/// Processing progress of an uploaded video, only returned to the owner
+/// https://developers.google.com/youtube/v3/docs/videos#processingDetails
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoProcessingDetails {
+    /// `processing`, `succeeded`, `failed` or `terminated`.
+    pub processing_status: String,
+    pub processing_progress: Option<ProcessingProgress>,
+    pub processing_failure_reason: Option<String>,
+    pub file_details_availability: Option<String>,
+    pub processing_issues_availability: Option<String>,
+    pub tag_suggestions_availability: Option<String>,
+    pub editor_suggestions_availability: Option<String>,
+    pub thumbnails_availability: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessingProgress {
+    #[serde(default, with = "option_u64")]
+    pub parts_total: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub parts_processed: Option<u64>,
+    #[serde(default, with = "option_u64")]
+    pub time_left_ms: Option<u64>,
+}
+
+/// Suggestions to improve the uploaded video, only returned to the owner
+/// https://developers.google.com/youtube/v3/docs/videos#suggestions
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSuggestions {
+    pub processing_errors: Option<Vec<String>>,
+    pub processing_warnings: Option<Vec<String>>,
+    pub processing_hints: Option<Vec<String>>,
+    pub tag_suggestions: Option<Vec<TagSuggestion>>,
+    pub editor_suggestions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TagSuggestion {
+    pub tag: String,
+    pub category_restricts: Option<Vec<String>>,
+}
+
+/// Live broadcast metadata https://developers.google.com/youtube/v3/docs/videos#liveStreamingDetails
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoLiveStreamingDetails {
+    pub actual_start_time: Option<String>,
+    pub actual_end_time: Option<String>,
+    pub scheduled_start_time: Option<String>,
+    pub scheduled_end_time: Option<String>,
+    #[serde(default, with = "option_u64")]
+    pub concurrent_viewers: Option<u64>,
+    pub active_live_chat_id: Option<String>,
+}
+
+/// Rating of a video by the authenticated user, see https://developers.google.com/youtube/v3/docs/videos/rate .
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Rating {
+    Like,
+    Dislike,
+    /// Removes the rating when rating a video, or the video is not rated
+    None,
+    /// Returned by `getRating` when the rating is unknown
+    Unspecified,
+}
+
+impl Rating {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rating::Like => "like",
+            Rating::Dislike => "dislike",
+            Rating::None => "none",
+            Rating::Unspecified => "unspecified",
+        }
+    }
+}
+
+/// Response of videos.getRating https://developers.google.com/youtube/v3/docs/videos/getRating#response
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoGetRatingResponse {
+    pub kind: String,
+    pub etag: String,
+    pub items: Vec<VideoRating>,
+}
+
+impl VideoGetRatingResponse {
+    /// Returns the rating of the given video, when it was requested.
+    pub fn rating(&self, video_id: &str) -> Option<Rating> {
+        self.items
+            .iter()
+            .find(|item| item.video_id == video_id)
+            .map(|item| item.rating)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoRating {
+    pub video_id: String,
+    pub rating: Rating,
+}
+
+/// Report of an abusive video https://developers.google.com/youtube/v3/docs/videos/reportAbuse#request-body
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoAbuseReport {
+    pub video_id: String,
+    /// Reason from `videoAbuseReportReasons.list`
+    pub reason_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_reason_id: Option<String>,
+    /// Additional information about the abuse
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+    /// Language spoken by the reporter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl VideoAbuseReport {
+    pub fn new(video_id: &str, reason_id: &str) -> Self {
+        VideoAbuseReport {
+            video_id: video_id.to_string(),
+            reason_id: reason_id.to_string(),
+            ..Default::default()
+        }
+    }
+    pub fn with_secondary_reason_id(mut self, secondary_reason_id: &str) -> Self {
+        self.secondary_reason_id = Some(secondary_reason_id.to_string());
+        self
+    }
+    pub fn with_comments(mut self, comments: &str) -> Self {
+        self.comments = Some(comments.to_string());
+        self
+    }
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_every_part() {
+        let video = json!({
+            "kind": "youtube#video",
+            "etag": "etag",
+            "id": "E6UTz_Doic8",
+            "snippet": {
+                "publishedAt": "2021-04-01T10:00:00Z",
+                "channelId": "UC123",
+                "title": "Title",
+                "description": "Description",
+                "thumbnails": {
+                    "default": {"url": "https://i.ytimg.com/vi/E6UTz_Doic8/default.jpg", "width": 120, "height": 90},
+                    "high": {"url": "https://i.ytimg.com/vi/E6UTz_Doic8/hqdefault.jpg", "width": 480, "height": 360}
+                },
+                "channelTitle": "Channel",
+                "categoryId": "28",
+                "localized": {"title": "Title", "description": "Description"}
+            },
+            "contentDetails": {
+                "duration": "PT15M33S",
+                "dimension": "2d",
+                "definition": "hd",
+                "caption": "false",
+                "licensedContent": true,
+                "regionRestriction": {"blocked": ["DE"]},
+                "contentRating": {"ytRating": "ytAgeRestricted", "eirinRating": "eirinR15plus"},
+                "projection": "rectangular"
+            },
+            "status": {"uploadStatus": "processed", "privacyStatus": "unlisted", "license": "youtube", "embeddable": true},
+            "statistics": {"viewCount": "1234567890123", "likeCount": "42", "favoriteCount": "0", "commentCount": "7"},
+            "player": {"embedHtml": "<iframe></iframe>"},
+            "topicDetails": {"topicCategories": ["https://en.wikipedia.org/wiki/Technology"]},
+            "recordingDetails": {"location": {"latitude": 59.9, "longitude": 10.7}},
+            "fileDetails": {"fileName": "video.mp4", "fileSize": "1048576", "videoStreams": [{"widthPixels": 1920, "heightPixels": 1080}]},
+            "processingDetails": {"processingStatus": "succeeded", "processingProgress": {"partsTotal": "10", "partsProcessed": "10"}},
+            "suggestions": {"tagSuggestions": [{"tag": "rust"}]},
+            "liveStreamingDetails": {"concurrentViewers": "12"},
+            "localizations": {"fr": {"title": "Titre", "description": "Description"}}
+        });
+        let video: YoutubeVideo = serde_json::from_value(video).unwrap();
+
+        let snippet = video.snippet.unwrap();
+        assert_eq!(snippet.thumbnails.unwrap().best().unwrap().width, Some(480));
+        let content_details = video.content_details.unwrap();
+        assert_eq!(content_details.duration, "PT15M33S");
+        let rating = content_details.content_rating.unwrap();
+        assert_eq!(rating.yt_rating.as_deref(), Some("ytAgeRestricted"));
+        assert_eq!(rating.other_ratings["eirinRating"], "eirinR15plus");
+        assert_eq!(
+            video.status.unwrap().privacy_status,
+            Some(PrivacyStatus::Unlisted)
+        );
+        let statistics = video.statistics.unwrap();
+        assert_eq!(statistics.view_count, Some(1_234_567_890_123));
+        assert_eq!(statistics.dislike_count, None);
+        assert_eq!(video.file_details.unwrap().file_size, Some(1_048_576));
+        assert_eq!(video.live_streaming_details.unwrap().concurrent_viewers, Some(12));
+        assert_eq!(video.localizations.unwrap()["fr"].title, "Titre");
+    }
+    #[test]
+    fn test_deserialize_partial_content_details() {
+        let video: YoutubeVideo = serde_json::from_value(json!({
+            "kind": "youtube#video",
+            "etag": "etag",
+            "id": "abc",
+            "contentDetails": {"duration": "P0D", "licensedContent": false}
+        }))
+        .unwrap();
+        let details = video.content_details.unwrap();
+        assert_eq!(details.duration, "P0D");
+        assert!(details.definition.is_empty());
+        assert!(details.projection.is_empty());
+    }
+    #[test]
+    fn test_serialize_status_skips_missing_fields() {
+        let status = VideoStatus {
+            privacy_status: Some(PrivacyStatus::Unlisted),
+            ..VideoStatus::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            json!({"privacyStatus": "unlisted"})
+        );
+    }
+    #[test]
+    fn test_serialize_recording_details_skips_missing_fields() {
+        let details = VideoRecordingDetails {
+            location: Some(GeoPoint {
+                latitude: 59.91,
+                longitude: 10.75,
+                altitude: None,
+            }),
+            ..VideoRecordingDetails::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&details).unwrap(),
+            json!({"location": {"latitude": 59.91, "longitude": 10.75}})
+        );
+    }
+    #[test]
+    fn test_serialize_skips_missing_parts() {
+        let video = YoutubeVideo {
+            statistics: Some(VideoStatistics {
+                view_count: Some(3),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&video).unwrap();
+        assert!(value.get("snippet").is_none());
+        assert_eq!(value["statistics"]["viewCount"], "3");
+    }
 }
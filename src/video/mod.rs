@@ -1,11 +1,15 @@
-
-use crate::wasm_bindgen::JsValue;
-
-use seed::fetch::{Method, Request};
-use crate::response::{YoutubeListResponse, build_response};
-use crate::video::data::YoutubeVideo;
-use crate::client::ClientError;
-
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::response::{
+    build_empty_response, build_response, paginate_items, paginate_pages, YoutubeListResponse,
+};
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use crate::upload::multipart::MultipartRelated;
+use crate::upload::resumable::ResumableUpload;
+use crate::upload::source::MediaSource;
+use crate::video::data::{VideoAbuseReport, VideoGetRatingResponse, YoutubeVideo};
+use crate::video::query::ListVideos;
+use futures::Stream;
 
 pub  mod  query;
 pub  mod  data;
@@ -14,15 +18,25 @@ pub  mod  prelude {
     pub  use crate::video::{data:: * ,VideoEndPoint , query::*};
 }
 
-pub struct VideoEndPoint {
+pub struct VideoEndPoint<'a, T> {
+    client: &'a Client<T>,
     url: String,
 }
 
-impl VideoEndPoint {
-    /// Complete the url.s
-    pub fn new(url: String) -> Self {
-        let url = url.replace("API", "videos");
-        VideoEndPoint { url }
+impl<'a, T> Clone for VideoEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        VideoEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> VideoEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("videos");
+        VideoEndPoint { client, url }
     }
     /// Returns a list of videos that match the API request parameters.
     /// Get the list with additional parameter using format -> key=value&;
@@ -31,9 +45,36 @@ impl VideoEndPoint {
         &self,
         query_search: &str,
     ) -> Result<YoutubeListResponse<YoutubeVideo>, ClientError> {
-        let url = format!("{}&{}", &self.url.clone(), query_search);
-        let request = Request::new(url).method(Method::Get);
-        build_response(request).await
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Stream the videos of every page of the list, following `nextPageToken` until the last
+    /// page or until `max_items` videos were returned.
+    pub fn paginate(
+        &self,
+        query: ListVideos,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<YoutubeVideo, ClientError>> + 'a {
+        paginate_items(self.paginate_pages(query), max_items)
+    }
+
+    /// Stream every page of the list, following `nextPageToken` until the last page.
+    pub fn paginate_pages(
+        &self,
+        query: ListVideos,
+    ) -> impl Stream<Item = Result<YoutubeListResponse<YoutubeVideo>, ClientError>> + 'a {
+        let endpoint = self.clone();
+        paginate_pages(move |page_token| {
+            let endpoint = endpoint.clone();
+            let query = match page_token {
+                Some(page_token) => query.clone().parse_page_token(&page_token),
+                None => query.clone(),
+            }
+            .build_query_parameters();
+            async move { endpoint.list(query.get_query_params()).await }
+        })
     }
 
     /// Uploads a video to YouTube and optionally sets the video's metadata.
@@ -44,9 +85,362 @@ impl VideoEndPoint {
         query_search: &str,
         requested_body: &str,
     ) -> Result<YoutubeVideo, ClientError> {
-        let url = format!("{}&{}", &self.url.clone(), query_search);
-        let body = JsValue::from(requested_body);
-        let request = Request::new(url).method(Method::Post).body(&body);
-        build_response(request).await
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url)
+            .header("Content-Type", "application/json")
+            .body(requested_body.as_bytes().to_vec());
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Updates the metadata of a video, the parts sent replace the existing ones.
+    /// The video must contain its id.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/update .
+    pub async fn update(
+        &self,
+        query_search: &str,
+        video: &YoutubeVideo,
+    ) -> Result<YoutubeVideo, ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Put, url).json(video).map_err(invalid_body)?;
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Deletes a video, the Api answers with an empty body.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/delete .
+    pub async fn delete(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Delete, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Adds or removes the rating of the authenticated user, the Api answers with an empty body.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/rate .
+    pub async fn rate(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.endpoint_url("videos/rate"),
+            query_search
+        );
+        let request = HttpRequest::new(Method::Post, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Retrieves the ratings of the authenticated user for a list of videos.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/getRating .
+    pub async fn get_rating(
+        &self,
+        query_search: &str,
+    ) -> Result<VideoGetRatingResponse, ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.endpoint_url("videos/getRating"),
+            query_search
+        );
+        let request = HttpRequest::new(Method::Get, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Reports a video for containing abusive content, the Api answers with an empty body.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/reportAbuse .
+    pub async fn report_abuse(
+        &self,
+        query_search: &str,
+        report: &VideoAbuseReport,
+    ) -> Result<(), ClientError> {
+        let mut url = self.client.endpoint_url("videos/reportAbuse");
+        if !query_search.is_empty() {
+            url = format!("{}&{}", url, query_search);
+        }
+        let request = HttpRequest::new(Method::Post, url).json(report).map_err(invalid_body)?;
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Uploads a short video and its metadata in a single request, prefer `insert_resumable`
+    /// for large files as the whole media is loaded in memory.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/videos/insert .
+    pub async fn insert_multipart<M: MediaSource>(
+        &self,
+        query_search: &str,
+        video: &YoutubeVideo,
+        mut media: M,
+    ) -> Result<YoutubeVideo, ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.upload_url("videos", "multipart"),
+            query_search
+        );
+        let bytes = media.read(0, media.size() as usize).await?;
+        let request = MultipartRelated::new()
+            .metadata(video).map_err(invalid_body)?
+            .media(media.content_type(), &bytes)
+            .into_request(Method::Post, url);
+        build_response(self.client.transport(), request).await
+    }
+
+    /// Open a resumable upload session for the video file and its metadata, the returned
+    /// upload sends the media with `upload` and can be resumed after an interruption.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/guides/using_resumable_upload_protocol .
+    pub async fn insert_resumable<M: MediaSource>(
+        &self,
+        query_search: &str,
+        video: &YoutubeVideo,
+        media: M,
+    ) -> Result<ResumableUpload<'a, T, M, YoutubeVideo>, ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.upload_url("videos", "resumable"),
+            query_search
+        );
+        ResumableUpload::initiate(self.client, Method::Post, url, video, media).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, json, MockTransport};
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    fn paginated_client() -> Client<MockTransport> {
+        let page = |ids: &[&str], next: Option<&str>| {
+            let items = ids.iter().map(|id| fixtures::video(id, id)).collect();
+            json(200, fixtures::video_list(items, next))
+        };
+        let transport = MockTransport::new()
+            .respond(Method::Get, "videos", page(&["a", "b"], Some("PAGE2")))
+            .respond_when(
+                Method::Get,
+                "videos",
+                &[("pageToken", "PAGE2")],
+                page(&["c", "d"], Some("PAGE3")),
+            )
+            .respond_when(
+                Method::Get,
+                "videos",
+                &[("pageToken", "PAGE3")],
+                page(&["e"], None),
+            );
+        Client::with_transport("token", "key", transport)
+    }
+
+    fn ids(videos: Vec<Result<YoutubeVideo, ClientError>>) -> Vec<String> {
+        videos.into_iter().map(|video| video.unwrap().id).collect()
+    }
+
+    #[test]
+    fn test_paginate_until_last_page() {
+        let client = paginated_client();
+        let query = ListVideos::create_with_chart_most_popular().parse_max_results(2);
+        let videos = block_on(client.video().paginate(query, None).collect::<Vec<_>>());
+        assert_eq!(ids(videos), vec!["a", "b", "c", "d", "e"]);
+        let requests = client.transport().requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[2].url.contains("maxResults=2&pageToken=PAGE3"));
+    }
+    #[test]
+    fn test_paginate_stops_at_max_items() {
+        let client = paginated_client();
+        let query = ListVideos::create_with_chart_most_popular();
+        let videos = block_on(client.video().paginate(query, Some(3)).collect::<Vec<_>>());
+        assert_eq!(ids(videos), vec!["a", "b", "c"]);
+        assert_eq!(client.transport().requests().len(), 2);
+    }
+    #[test]
+    fn test_insert_resumable_sends_metadata() {
+        use crate::resource::PrivacyStatus;
+        use crate::transport::HttpResponse;
+        use crate::upload::source::BytesSource;
+        use crate::video::data::{VideoSnippet, VideoStatus};
+
+        let session = "https://www.googleapis.com/upload/youtube/v3/videos?upload_id=xyz";
+        let transport = MockTransport::new()
+            .respond(
+                Method::Post,
+                "videos",
+                HttpResponse::new(200, Vec::new()).header("Location", session),
+            )
+            .respond(
+                Method::Put,
+                "videos",
+                json(200, fixtures::video("abc", "Holidays").to_string()),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let video = YoutubeVideo {
+            snippet: Some(VideoSnippet {
+                title: "Holidays".to_string(),
+                ..VideoSnippet::default()
+            }),
+            status: Some(VideoStatus {
+                privacy_status: Some(PrivacyStatus::Private),
+                ..VideoStatus::default()
+            }),
+            ..YoutubeVideo::default()
+        };
+        let media = BytesSource::new(b"video".to_vec(), "video/mp4");
+        let mut upload = block_on(client.video().insert_resumable(
+            "part=snippet,status",
+            &video,
+            media,
+        ))
+        .unwrap();
+        assert_eq!(block_on(upload.upload()).unwrap().id, "abc");
+
+        let requests = client.transport().requests();
+        assert!(requests[0]
+            .url
+            .starts_with("https://www.googleapis.com/upload/youtube/v3/videos?"));
+        assert!(requests[0].url.ends_with("uploadType=resumable&part=snippet,status"));
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(
+            body["snippet"],
+            serde_json::json!({"title": "Holidays", "description": ""})
+        );
+        assert!(body.get("id").is_none());
+        assert_eq!(requests[1].get_header("Content-Range"), Some("bytes 0-4/5"));
+    }
+    #[test]
+    fn test_insert_multipart() {
+        use crate::upload::source::BytesSource;
+
+        let transport = MockTransport::new().respond(
+            Method::Post,
+            "videos",
+            json(200, fixtures::video("abc", "Clip").to_string()),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let media = BytesSource::new(b"clip".to_vec(), "video/mp4");
+        let video = block_on(client.video().insert_multipart(
+            "part=snippet",
+            &YoutubeVideo::default(),
+            media,
+        ))
+        .unwrap();
+        assert_eq!(video.id, "abc");
+        let request = client.transport().last_request().unwrap();
+        assert!(request.url.contains("uploadType=multipart&part=snippet"));
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+        assert!(body.contains("Content-Type: video/mp4\r\n\r\nclip\r\n"));
+    }
+    #[test]
+    fn test_rate_and_get_rating() {
+        use crate::video::data::Rating;
+        use crate::video::query::{GetRating, RateVideo};
+
+        let ratings = serde_json::json!({
+            "kind": "youtube#videoGetRatingResponse",
+            "etag": "etag",
+            "items": [{"videoId": "E6UTz_Doic8", "rating": "like"}]
+        });
+        let transport = MockTransport::new()
+            .respond(Method::Post, "videos/rate", json(204, ""))
+            .respond(Method::Get, "videos/getRating", json(200, ratings.to_string()));
+        let client = Client::with_transport("token", "key", transport);
+
+        let rate = RateVideo::like_video("E6UTz_Doic8")
+            .unwrap()
+            .build_query_params();
+        block_on(client.video().rate(rate.get_query_params())).unwrap();
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .starts_with("https://www.googleapis.com/youtube/v3/videos/rate?"));
+        assert!(request.url.ends_with("id=E6UTz_Doic8&rating=like"));
+
+        let query = GetRating::new(&["E6UTz_Doic8"])
+            .unwrap()
+            .build_query_parameters();
+        let response = block_on(client.video().get_rating(query.get_query_params())).unwrap();
+        assert_eq!(response.rating("E6UTz_Doic8"), Some(Rating::Like));
+        assert_eq!(response.rating("other"), None);
+    }
+    #[test]
+    fn test_update_and_delete() {
+        use crate::video::query::{DeleteVideo, UpdateVideos};
+
+        let transport = MockTransport::new()
+            .respond(
+                Method::Put,
+                "videos",
+                json(200, fixtures::video("abc", "Renamed").to_string()),
+            )
+            .respond(Method::Delete, "videos", json(204, ""))
+            .respond(
+                Method::Delete,
+                "videos",
+                json(404, fixtures::error(404, "videoNotFound", "Video not found")),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let video = YoutubeVideo {
+            id: "abc".to_string(),
+            ..YoutubeVideo::default()
+        };
+        let update = UpdateVideos::default().build_query_parameters();
+        let updated = block_on(client.video().update(update.get_query_params(), &video)).unwrap();
+        assert_eq!(updated.snippet.unwrap().title, "Renamed");
+        let body = client.transport().last_request().unwrap().body.unwrap();
+        assert_eq!(body, br#"{"id":"abc"}"#);
+
+        let delete = DeleteVideo::new("dQw4w9WgXcQ")
+            .unwrap()
+            .build_query_parameters();
+        block_on(client.video().delete(delete.get_query_params())).unwrap();
+        let result = block_on(client.video().delete(delete.get_query_params()));
+        assert!(matches!(result, Err(ClientError::Youtube(e)) if e.code() == 404));
+    }
+    #[test]
+    fn test_mutation_failure_without_envelope() {
+        use crate::transport::HttpResponse;
+        use crate::video::query::{DeleteVideo, RateVideo};
+
+        let transport = MockTransport::new()
+            .respond(Method::Delete, "videos", json(500, "{}"))
+            .respond(
+                Method::Post,
+                "videos/rate",
+                HttpResponse::new(503, b"<html>Unavailable</html>".to_vec()),
+            );
+        let client = Client::with_transport("token", "key", transport);
+        let delete = DeleteVideo::new("dQw4w9WgXcQ")
+            .unwrap()
+            .build_query_parameters();
+        let result = block_on(client.video().delete(delete.get_query_params()));
+        assert!(matches!(result, Err(ClientError::Status { status: 500, .. })));
+        let rate = RateVideo::like_video("dQw4w9WgXcQ")
+            .unwrap()
+            .build_query_params();
+        let result = block_on(client.video().rate(rate.get_query_params()));
+        assert!(matches!(result, Err(ClientError::Status { status: 503, .. })));
+    }
+    #[test]
+    fn test_report_abuse() {
+        use crate::video::query::ReportAbuse;
+
+        let transport =
+            MockTransport::new().respond(Method::Post, "videos/reportAbuse", json(204, ""));
+        let client = Client::with_transport("token", "key", transport);
+        let report = VideoAbuseReport::new("abc", "S").with_comments("Spam");
+        let query = ReportAbuse::default().build_query_parameters();
+        block_on(client.video().report_abuse(query.get_query_params(), &report)).unwrap();
+        let request = client.transport().last_request().unwrap();
+        assert!(!request.url.ends_with('&'));
+        assert_eq!(
+            request.body.unwrap(),
+            br#"{"videoId":"abc","reasonId":"S","comments":"Spam"}"#
+        );
+    }
+    #[test]
+    fn test_paginate_pages_stops_on_error() {
+        let transport = MockTransport::new().respond(
+            Method::Get,
+            "videos",
+            json(400, fixtures::error(400, "invalidPageToken", "Invalid page token")),
+        );
+        let client = Client::with_transport("token", "key", transport);
+        let query = ListVideos::create_with_chart_most_popular();
+        let pages = block_on(client.video().paginate_pages(query).collect::<Vec<_>>());
+        assert_eq!(pages.len(), 1);
+        assert!(matches!(pages[0], Err(ClientError::Youtube(_))));
     }
 }
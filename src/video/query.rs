@@ -1,90 +1,301 @@
+use crate::client::ClientError;
+use crate::part::{Part, PartSet};
+use crate::query::QueryString;
+use crate::video::data::Rating;
 use serde::{Deserialize,Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Default)]
-pub struct Part {
-    /// Specifies a comma-separated list of one or more video resource properties that the API
-    /// response will include
-    part: String,
+/// The parts of the video resource, see https://developers.google.com/youtube/v3/docs/videos#resource .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VideoPart {
+    Snippet,
+    Statistics,
+    ContentDetails,
+    Id,
+    LiveStreamingDetails,
+    Localizations,
+    Player,
+    RecordingDetails,
+    Status,
+    TopicDetails,
+    FileDetails,
+    ProcessingDetails,
+    Suggestions,
 }
 
-impl Part {
-    /// Parse selection of parameters
-    pub fn new(part: &str) -> Part {
-        Part::default().parse_part(part)
-    }
-    /// Parse every parameter
-    pub fn new_with_every_parameter() -> Part {
-        Part::default().parse_part(
-            //TODO: Separate into their own function instead of string
-            "contentDetails,fileDetails,id,liveStreamingDetails,localizations,player,
-            processingDetails,recordingDetails,snippet,statistics,status,suggestions,topicDetails",
+impl Part for VideoPart {
+    const ALL: &'static [Self] = &[
+        VideoPart::Snippet,
+        VideoPart::Statistics,
+        VideoPart::ContentDetails,
+        VideoPart::Id,
+        VideoPart::LiveStreamingDetails,
+        VideoPart::Localizations,
+        VideoPart::Player,
+        VideoPart::RecordingDetails,
+        VideoPart::Status,
+        VideoPart::TopicDetails,
+        VideoPart::FileDetails,
+        VideoPart::ProcessingDetails,
+        VideoPart::Suggestions,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            VideoPart::Snippet => "snippet",
+            VideoPart::Statistics => "statistics",
+            VideoPart::ContentDetails => "contentDetails",
+            VideoPart::Id => "id",
+            VideoPart::LiveStreamingDetails => "liveStreamingDetails",
+            VideoPart::Localizations => "localizations",
+            VideoPart::Player => "player",
+            VideoPart::RecordingDetails => "recordingDetails",
+            VideoPart::Status => "status",
+            VideoPart::TopicDetails => "topicDetails",
+            VideoPart::FileDetails => "fileDetails",
+            VideoPart::ProcessingDetails => "processingDetails",
+            VideoPart::Suggestions => "suggestions",
+        }
+    }
+
+    fn requires_owner(&self) -> bool {
+        matches!(
+            self,
+            VideoPart::FileDetails | VideoPart::ProcessingDetails | VideoPart::Suggestions
         )
     }
-    /// Parse part to its field
-    pub fn parse_part(mut self, part: &str) -> Self {
-        self.part = part.to_string();
-        self
+}
+
+/// Parts written by insert and update, the others are read-only.
+fn writable_parts() -> PartSet<VideoPart> {
+    PartSet::new()
+        .with(VideoPart::Snippet)
+        .with(VideoPart::Status)
+        .with(VideoPart::Localizations)
+        .with(VideoPart::RecordingDetails)
+}
+
+/// Chart that can be retrieved with `videos.list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Chart {
+    /// Most popular videos for the content region and video category
+    MostPopular,
+}
+
+impl Chart {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Chart::MostPopular => "mostPopular",
+        }
     }
-    /// Returns the part
-    pub fn get_part(&self) -> &str {
-        &self.part
+}
+
+/// Rating of the authenticated user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MyRating {
+    Like,
+    Dislike,
+}
+
+impl MyRating {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MyRating::Like => "like",
+            MyRating::Dislike => "dislike",
+        }
     }
 }
-#[derive(Debug, Deserialize, Serialize, Default)]
+
+/// Filter selecting the listed videos, the Api expects exactly one of them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum VideoFilter {
+    /// Videos of a chart
+    Chart(Chart),
+    /// Videos rated by the authenticated user
+    MyRating(MyRating),
+    /// Videos with the given ids
+    Id(Vec<String>),
+}
+
+impl Default for VideoFilter {
+    fn default() -> Self {
+        VideoFilter::Chart(Chart::MostPopular)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct ListVideos {
-    /// Identifies the desired chart
-    chart: String,
-    /// Set to 'like'/'dislike' to sort by user rating
-    my_rating: String,
+    /// Video resource properties that the API response will include
+    part: PartSet<VideoPart>,
+    /// Selects the videos to list
+    filter: VideoFilter,
+    /// Language used for the localized metadata
+    hl: Option<String>,
+    /// Maximum height of the embedded player
+    max_height: Option<u32>,
+    /// Maximum width of the embedded player
+    max_width: Option<u32>,
+    /// Maximum number of items per page, from 1 to 50
+    max_results: Option<u8>,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Page to retrieve
+    page_token: Option<String>,
+    /// Region of the chart, ISO 3166-1 alpha-2 country code
+    region_code: Option<String>,
+    /// Video category of the chart
+    video_category_id: Option<String>,
     /// The full request URL
     query_params: String,
 }
 
+/// Largest page the Api returns.
+const MAX_RESULTS: u8 = 50;
+
 impl ListVideos {
+    /// Create with the given filter, filtering by ids requires at least one
+    pub fn new(filter: VideoFilter) -> Result<ListVideos, ClientError> {
+        ListVideos::default().parse_filter(filter)
+    }
     /// Sets my_rating value to 'like' to list user's liked videos
     pub fn create_with_my_rating_like() -> ListVideos {
-        ListVideos::default().parse_my_rating("like")
+        ListVideos::default().parse_my_rating(MyRating::Like)
     }
-    /// Sets my_rating value to 'mostPopular' to list most popular videos on YouTube at the moment
+    /// Sets chart value to 'mostPopular' to list most popular videos on YouTube at the moment
     pub fn create_with_chart_most_popular() -> ListVideos {
-        ListVideos::default().parse_chart("mostPopular")
+        ListVideos::default().parse_chart(Chart::MostPopular)
+    }
+    /// Create to list the videos with the given ids, at least one is required
+    pub fn create_with_ids(ids: &[&str]) -> Result<ListVideos, ClientError> {
+        ListVideos::default().parse_ids(ids)
+    }
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<VideoPart>) -> Self {
+        self.part = part;
+        self
+    }
+    /// Parse filter to its field, replacing the previous one
+    pub fn parse_filter(mut self, filter: VideoFilter) -> Result<Self, ClientError> {
+        if filter == VideoFilter::Id(Vec::new()) {
+            return Err(ClientError::InvalidRequest(
+                "at least one video id is required".to_string(),
+            ));
+        }
+        self.filter = filter;
+        Ok(self)
+    }
+    /// Filter by chart, replacing the previous filter
+    pub fn parse_chart(mut self, chart: Chart) -> Self {
+        self.filter = VideoFilter::Chart(chart);
+        self
+    }
+    /// Filter by rating of the authenticated user, replacing the previous filter
+    pub fn parse_my_rating(mut self, my_rating: MyRating) -> Self {
+        self.filter = VideoFilter::MyRating(my_rating);
+        self
+    }
+    /// Filter by ids, replacing the previous filter
+    pub fn parse_ids(self, ids: &[&str]) -> Result<Self, ClientError> {
+        self.parse_filter(VideoFilter::Id(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+    /// Parse hl to its field
+    pub fn parse_hl(mut self, hl: &str) -> Self {
+        self.hl = Some(hl.to_string());
+        self
+    }
+    /// Parse max_height to its field
+    pub fn parse_max_height(mut self, max_height: u32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+    /// Parse max_width to its field
+    pub fn parse_max_width(mut self, max_width: u32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+    /// Parse max_results to its field, at most 50 and ignored when filtering by ids
+    pub fn parse_max_results(mut self, max_results: u8) -> Self {
+        self.max_results = Some(max_results.min(MAX_RESULTS));
+        self
     }
-    /// Parse chart to its field
-    pub fn parse_chart(mut self, chart: &str) -> Self {
-        self.chart = chart.to_string();
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
         self
     }
-    /// Parse my_rating to its field
-    pub fn parse_my_rating(mut self, my_rating: &str) -> Self {
-        self.my_rating = my_rating.to_string();
+    /// Parse page_token to its field, ignored when filtering by ids
+    pub fn parse_page_token(mut self, page_token: &str) -> Self {
+        self.page_token = Some(page_token.to_string());
         self
     }
-    /// Returns the chart
-    pub fn get_chart(&self) -> &str {
-        &self.chart
+    /// Parse region_code to its field, only used with a chart
+    pub fn parse_region_code(mut self, region_code: &str) -> Self {
+        self.region_code = Some(region_code.to_string());
+        self
+    }
+    /// Parse video_category_id to its field, only used with a chart
+    pub fn parse_video_category_id(mut self, video_category_id: &str) -> Self {
+        self.video_category_id = Some(video_category_id.to_string());
+        self
+    }
+    /// Returns the part
+    pub fn get_part(&self) -> &PartSet<VideoPart> {
+        &self.part
+    }
+    /// Returns the filter
+    pub fn get_filter(&self) -> &VideoFilter {
+        &self.filter
     }
-    /// Returns rating, (like/dislike)
-    pub fn get_my_rating(&self) -> &str {
-        &self.my_rating
+    /// Returns the chart, if filtering by chart
+    pub fn get_chart(&self) -> Option<Chart> {
+        match self.filter {
+            VideoFilter::Chart(chart) => Some(chart),
+            _ => None,
+        }
+    }
+    /// Returns rating, (like/dislike), if filtering by rating
+    pub fn get_my_rating(&self) -> Option<MyRating> {
+        match self.filter {
+            VideoFilter::MyRating(my_rating) => Some(my_rating),
+            _ => None,
+        }
     }
     /// Build and assign the query parameters
     pub fn build_query_parameters(mut self) -> Self {
-        let part = Part::new(
-            // Required owner of video: fileDetails, processingDetails, suggestions
-            "snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails",
-        );
-
-        #[allow(unused_assignments)]
-        let mut query_params = String::new();
-        // Check if created with chart or my_rating
-        if self.my_rating.is_empty() && !self.chart.is_empty() {
-            query_params = "".to_string() + "part=" + part.get_part() + "&chart=" + &self.chart;
-        } else if !self.my_rating.is_empty() && self.chart.is_empty() {
-            query_params =
-                "".to_string() + "part=" + part.get_part() + "&my_rating=" + &self.my_rating;
-        } else {
-            panic!("Expected one of: chart, my_rating")
-        };
+        let mut query = QueryString::new().push("part", &self.part);
+        match &self.filter {
+            VideoFilter::Chart(chart) => {
+                query = query
+                    .push("chart", chart.as_str())
+                    .push_opt("regionCode", self.region_code.as_ref())
+                    .push_opt("videoCategoryId", self.video_category_id.as_ref());
+            }
+            VideoFilter::MyRating(my_rating) => {
+                query = query.push("myRating", my_rating.as_str());
+            }
+            VideoFilter::Id(ids) => {
+                query = query.push("id", ids.join(","));
+            }
+        }
+        // Pagination is not supported when filtering by ids
+        if !matches!(self.filter, VideoFilter::Id(_)) {
+            query = query
+                .push_opt("maxResults", self.max_results)
+                .push_opt("pageToken", self.page_token.as_ref());
+        }
+        let query_params = query
+            .push_opt("hl", self.hl.as_ref())
+            .push_opt("maxHeight", self.max_height)
+            .push_opt("maxWidth", self.max_width)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
 
         self.query_params = query_params;
         self
@@ -94,19 +305,30 @@ impl ListVideos {
         &self.query_params
     }
 }
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InsertVideos {
+    /// Video resource properties that are set and returned
+    part: PartSet<VideoPart>,
     /// Query parameters to be used in URL
     query_params: String,
 }
+impl Default for InsertVideos {
+    fn default() -> Self {
+        InsertVideos {
+            part: writable_parts(),
+            query_params: String::new(),
+        }
+    }
+}
 impl InsertVideos {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<VideoPart>) -> Self {
+        self.part = part;
+        self
+    }
     /// Build and assign the query parameters
     pub fn build_query_parameters(mut self) -> Self {
-        let part = Part::new(
-            // Required owner of video: fileDetails, processingDetails, suggestions
-            "snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails",
-        );
-        let query_params = "".to_string() + "part=" + part.get_part();
+        let query_params = QueryString::new().push("part", &self.part).to_string();
 
         self.query_params = query_params;
         self
@@ -115,49 +337,81 @@ impl InsertVideos {
         &self.query_params
     }
 }
-#[derive(Debug, Deserialize, Serialize, Default)]
+/// Returns an error unless the id has the 11 letters, digits, `-` or `_` of a video id.
+pub fn validate_video_id(id: &str) -> Result<(), ClientError> {
+    let valid = id.len() == 11
+        && id
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ClientError::InvalidRequest(format!(
+            "invalid video id {:?}",
+            id
+        )))
+    }
+}
+
+/// `unspecified` is only returned by getRating, videos.rate rejects it.
+fn validate_rating(rating: Rating) -> Result<(), ClientError> {
+    if rating == Rating::Unspecified {
+        Err(ClientError::InvalidRequest(
+            "a video cannot be rated unspecified".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RateVideo {
     /// ID of the YouTube video to be rated
     id: String,
     /// Rating to record
-    rating: String,
+    rating: Rating,
     /// Query parameters to be used in URL
     query_params: String,
 }
 impl RateVideo {
+    /// Rate the video, `Rating::None` removes the rating.
+    pub fn new(id: &str, rating: Rating) -> Result<RateVideo, ClientError> {
+        validate_video_id(id)?;
+        validate_rating(rating)?;
+        Ok(RateVideo {
+            id: id.to_string(),
+            rating,
+            query_params: String::new(),
+        })
+    }
     /// Create with value 'like' as rating
-    pub fn like_video() -> RateVideo {
-        RateVideo::default()
-            .parse_rating("like")
-            .parse_id("E6UTz_Doic8")
+    pub fn like_video(id: &str) -> Result<RateVideo, ClientError> {
+        RateVideo::new(id, Rating::Like)
     }
     /// Create with value 'dislike' as rating
-    pub fn dislike_video() -> RateVideo {
-        RateVideo::default()
-            .parse_rating("dislike")
-            .parse_id("E6UTz_Doic8")
-    }
-    /// Parse id to its field
-    pub fn parse_id(mut self, id: &str) -> Self {
-        self.id = id.to_string();
-        self
+    pub fn dislike_video(id: &str) -> Result<RateVideo, ClientError> {
+        RateVideo::new(id, Rating::Dislike)
     }
     /// Parse rating to its field
-    pub fn parse_rating(mut self, rating: &str) -> Self {
-        self.rating = rating.to_string();
-        self
+    pub fn parse_rating(mut self, rating: Rating) -> Result<Self, ClientError> {
+        validate_rating(rating)?;
+        self.rating = rating;
+        Ok(self)
     }
     /// Returns the id
     pub fn get_id(&self) -> &str {
         &self.id
     }
     /// Returns the rating
-    pub fn get_rating(&self) -> &str {
-        &self.rating
+    pub fn get_rating(&self) -> Rating {
+        self.rating
     }
     /// Build and assign the query parameters
     pub fn build_query_params(mut self) -> Self {
-        let query_params = "".to_string() + "id=" + &self.id + "&rating=" + &self.rating;
+        let query_params = QueryString::new()
+            .push("id", &self.id)
+            .push("rating", self.rating.as_str())
+            .to_string();
 
         self.query_params = query_params;
         self
@@ -168,18 +422,30 @@ impl RateVideo {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct UpdateVideos {
+    /// Video resource properties that are updated and returned
+    part: PartSet<VideoPart>,
     /// Query parameters to be used in URL
     query_params: String,
 }
+impl Default for UpdateVideos {
+    fn default() -> Self {
+        UpdateVideos {
+            part: writable_parts(),
+            query_params: String::new(),
+        }
+    }
+}
 impl UpdateVideos {
+    /// Parse part to its field
+    pub fn parse_part(mut self, part: PartSet<VideoPart>) -> Self {
+        self.part = part;
+        self
+    }
     /// Build and assign the query parameters
     pub fn build_query_parameters(mut self) -> Self {
-        let part = Part::new(
-            "snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails",
-        );
-        let query_params = "".to_string() + "part=" + part.get_part();
+        let query_params = QueryString::new().push("part", &self.part).to_string();
 
         self.query_params = query_params;
         self
@@ -189,35 +455,48 @@ impl UpdateVideos {
         &self.query_params
     }
 }
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GetRating {
-    /// ID of the YouTube video retrieving data from
-    id: String,
+    /// IDs of the YouTube videos retrieving the ratings of
+    ids: Vec<String>,
     /// Query parameters to be used in URL
     query_params: String,
 }
 
 impl GetRating {
-    pub fn new() -> GetRating {
-        GetRating::default().parse_id("E6UTz_Doic8")
-    }
-    /// Parse id to its field
-    pub fn parse_id(mut self, id: &str) -> Self {
-        self.id = id.to_string();
-        self
+    /// Get the ratings of one or several videos.
+    pub fn new(ids: &[&str]) -> Result<GetRating, ClientError> {
+        if ids.is_empty() {
+            return Err(ClientError::InvalidRequest(
+                "at least one video id is required".to_string(),
+            ));
+        }
+        for id in ids {
+            validate_video_id(id)?;
+        }
+        Ok(GetRating {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            query_params: String::new(),
+        })
     }
-    /// Returns the id
-    pub fn id(&self) -> &str {
-        &self.id
+    /// Returns the ids
+    pub fn ids(&self) -> &[String] {
+        &self.ids
     }
     /// Build and assign the query parameters
     pub fn build_query_parameters(mut self) -> Self {
-        let query_params = "".to_string() + "id=" + &self.id;
+        let query_params = QueryString::new()
+            .push("id", self.ids.join(","))
+            .to_string();
         self.query_params = query_params;
         self
     }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
 }
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DeleteVideo {
     /// ID of the YouTube video to be deleted
     id: String,
@@ -225,14 +504,13 @@ pub struct DeleteVideo {
     query_params: String,
 }
 impl DeleteVideo {
-    pub fn new() -> DeleteVideo {
-        //TODO: Make solution for gathering id
-        DeleteVideo::default().parse_id("E6UTz_Doic8")
-    }
-    /// Parse id to its field
-    pub fn parse_id(mut self, id: &str) -> Self {
-        self.id = id.to_string();
-        self
+    /// The Api deletes a single video per request.
+    pub fn new(id: &str) -> Result<DeleteVideo, ClientError> {
+        validate_video_id(id)?;
+        Ok(DeleteVideo {
+            id: id.to_string(),
+            query_params: String::new(),
+        })
     }
     /// Returns the id
     pub fn id(&self) -> &str {
@@ -240,10 +518,42 @@ impl DeleteVideo {
     }
     /// Build and assign the query parameters
     pub fn build_query_parameters(mut self) -> Self {
-        let query_params = "".to_string() + "id=" + &self.id;
+        let query_params = QueryString::new().push("id", &self.id).to_string();
         self.query_params = query_params;
         self
     }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ReportAbuse {
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+impl ReportAbuse {
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -256,48 +566,158 @@ mod tests {
     #[test]
     fn test_build_query_params_list_liked_videos() {
         let liked_videos = ListVideos::create_with_my_rating_like().build_query_parameters();
-        assert_eq!(liked_videos.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails&my_rating=like")
+        assert_eq!(liked_videos.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails&myRating=like")
+    }
+    #[test]
+    fn test_build_query_params_list_with_owner_parts() {
+        let parts = PartSet::new()
+            .with(VideoPart::Snippet)
+            .with(VideoPart::FileDetails);
+        let videos = ListVideos::create_with_my_rating_like()
+            .parse_part(parts)
+            .build_query_parameters();
+        assert!(videos.get_part().requires_owner());
+        assert_eq!(videos.query_params, "part=snippet,fileDetails&myRating=like")
     }
     #[test]
     fn test_build_query_params_insert_video() {
         let insert_video = InsertVideos::default().build_query_parameters();
-        assert_eq!(insert_video.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails")
+        assert_eq!(
+            insert_video.query_params,
+            "part=snippet,status,localizations,recordingDetails"
+        )
     }
     #[test]
     fn test_build_query_params_update_video() {
         let update_video = UpdateVideos::default().build_query_parameters();
-        assert_eq!(update_video.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails")
+        assert_eq!(
+            update_video.query_params,
+            "part=snippet,status,localizations,recordingDetails"
+        )
     }
     #[test]
     fn test_build_query_params_delete_video() {
-        let delete_video = DeleteVideo::new().build_query_parameters();
+        let delete_video = DeleteVideo::new("E6UTz_Doic8")
+            .unwrap()
+            .build_query_parameters();
         assert_eq!(delete_video.query_params, "id=E6UTz_Doic8")
     }
     #[test]
     fn test_build_query_params_get_rating() {
-        let get_rating = GetRating::new().build_query_parameters();
+        let get_rating = GetRating::new(&["E6UTz_Doic8"])
+            .unwrap()
+            .build_query_parameters();
         assert_eq!(get_rating.query_params, "id=E6UTz_Doic8")
     }
     #[test]
-    #[should_panic]
-    fn test_panic_with_both_chart_and_my_rating_values() {
-        let most_popular = ListVideos {
-            chart: "123".to_string(),
-            my_rating: "123".to_string(),
-            query_params: "".to_string(),
-        }
+    fn test_build_query_params_get_rating_of_several_videos() {
+        let get_rating = GetRating::new(&["E6UTz_Doic8", "dQw4w9WgXcQ"])
+            .unwrap()
             .build_query_parameters();
-        assert_eq!(most_popular.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails&chart=mostPopular")
+        assert_eq!(get_rating.query_params, "id=E6UTz_Doic8,dQw4w9WgXcQ")
+    }
+    #[test]
+    fn test_build_query_params_rate_video() {
+        let rate = RateVideo::new("dQw4w9WgXcQ", Rating::None)
+            .unwrap()
+            .build_query_params();
+        assert_eq!(rate.get_query_params(), "id=dQw4w9WgXcQ&rating=none")
+    }
+    #[test]
+    fn test_reject_unspecified_rating() {
+        assert!(matches!(
+            RateVideo::new("dQw4w9WgXcQ", Rating::Unspecified),
+            Err(ClientError::InvalidRequest(_))
+        ));
+        let rate = RateVideo::like_video("dQw4w9WgXcQ").unwrap();
+        assert!(rate.parse_rating(Rating::Unspecified).is_err());
     }
     #[test]
-    #[should_panic]
-    fn test_panic_with_neither_chart_nor_my_rating_values() {
-        let most_popular = ListVideos {
-            chart: "".to_string(),
-            my_rating: "".to_string(),
-            query_params: "".to_string(),
+    fn test_reject_malformed_video_ids() {
+        for id in &[
+            "",
+            "E6UTz_Doic",
+            "E6UTz_Doic8x",
+            "E6UTz Doic8",
+            "E6UTz_Doic&",
+            "é6UTz_Doic",
+        ] {
+            assert!(
+                matches!(validate_video_id(id), Err(ClientError::InvalidRequest(_))),
+                "{:?} should be rejected",
+                id
+            );
         }
+        assert!(validate_video_id("-_0aZ-_0aZ9").is_ok());
+        assert!(RateVideo::like_video("E6UTz_Doic").is_err());
+        assert!(DeleteVideo::new("").is_err());
+        assert!(GetRating::new(&[]).is_err());
+        assert!(ListVideos::create_with_ids(&[]).is_err());
+        assert!(ListVideos::new(VideoFilter::Id(Vec::new())).is_err());
+        assert!(ListVideos::default()
+            .parse_filter(VideoFilter::Id(Vec::new()))
+            .is_err());
+        assert!(GetRating::new(&["E6UTz_Doic8", "bad"]).is_err());
+    }
+    #[test]
+    fn test_build_query_params_list_chart_with_every_filter() {
+        let videos = ListVideos::create_with_chart_most_popular()
+            .parse_part(PartSet::new().with(VideoPart::Snippet))
+            .parse_region_code("NO")
+            .parse_video_category_id("28")
+            .parse_max_results(25)
+            .parse_page_token("CBkQAA")
+            .parse_hl("nb")
+            .parse_max_height(720)
+            .parse_max_width(1280)
+            .parse_on_behalf_of_content_owner("owner")
             .build_query_parameters();
-        assert_eq!(most_popular.query_params, "part=snippet,statistics,contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,status,topicDetails&chart=mostPopular")
+        assert_eq!(
+            videos.get_query_params(),
+            "part=snippet&chart=mostPopular&regionCode=NO&videoCategoryId=28&maxResults=25\
+             &pageToken=CBkQAA&hl=nb&maxHeight=720&maxWidth=1280&onBehalfOfContentOwner=owner"
+        )
+    }
+    #[test]
+    fn test_build_query_params_list_by_ids_ignores_chart_and_pagination() {
+        let videos = ListVideos::create_with_ids(&["E6UTz_Doic8", "dQw4w9WgXcQ"])
+            .unwrap()
+            .parse_part(PartSet::new().with(VideoPart::Id))
+            .parse_region_code("NO")
+            .parse_max_results(25)
+            .parse_page_token("CBkQAA")
+            .build_query_parameters();
+        assert_eq!(
+            videos.get_query_params(),
+            "part=id&id=E6UTz_Doic8,dQw4w9WgXcQ"
+        )
+    }
+    #[test]
+    fn test_clamp_max_results() {
+        let videos = ListVideos::create_with_my_rating_like()
+            .parse_max_results(200)
+            .build_query_parameters();
+        assert!(videos.get_query_params().ends_with("&myRating=like&maxResults=50"));
+    }
+    #[test]
+    fn test_build_query_params_encode_values() {
+        let videos = ListVideos::create_with_chart_most_popular()
+            .parse_part(PartSet::new().with(VideoPart::Snippet))
+            .parse_page_token("a+b/c=")
+            .build_query_parameters();
+        assert_eq!(
+            videos.get_query_params(),
+            "part=snippet&chart=mostPopular&pageToken=a%2Bb%2Fc%3D"
+        )
+    }
+    #[test]
+    fn test_last_filter_replaces_the_previous_one() {
+        let videos = ListVideos::create_with_chart_most_popular().parse_my_rating(MyRating::Dislike);
+        assert_eq!(videos.get_chart(), None);
+        assert_eq!(videos.get_my_rating(), Some(MyRating::Dislike));
+        assert_eq!(
+            ListVideos::default().get_filter(),
+            &VideoFilter::Chart(Chart::MostPopular)
+        );
     }
 }
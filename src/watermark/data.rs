@@ -0,0 +1,154 @@
+use crate::resource::option_u64;
+use serde::{Deserialize, Serialize};
+
+/// The watermark of a channel, see https://developers.google.com/youtube/v3/docs/watermarks#resource
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InvideoBranding {
+    /// Url of the image, set by YouTube
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub image_url: String,
+    /// Base64 encoded image, the image is rather sent as the media of `watermarks.set`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub image_bytes: String,
+    /// Channel the viewers are linked to when clicking the watermark
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_channel_id: Option<String>,
+    pub timing: InvideoTiming,
+    pub position: InvideoPosition,
+}
+
+impl InvideoBranding {
+    /// Create the metadata of a watermark shown at the given time and place.
+    pub fn new(timing: InvideoTiming, position: InvideoPosition) -> Self {
+        InvideoBranding {
+            timing,
+            position,
+            ..Default::default()
+        }
+    }
+    /// Link the watermark to a channel.
+    pub fn with_target_channel_id(mut self, channel_id: &str) -> Self {
+        self.target_channel_id = Some(channel_id.to_string());
+        self
+    }
+}
+
+/// When the watermark appears during the videos.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InvideoTiming {
+    #[serde(rename = "type")]
+    pub timing_type: TimingType,
+    /// Time in milliseconds from the start or the end of the video, depending on the type
+    #[serde(default, with = "option_u64", skip_serializing_if = "Option::is_none")]
+    pub offset_ms: Option<u64>,
+    /// How long the watermark stays in milliseconds, until the end of the video when missing
+    #[serde(default, with = "option_u64", skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl InvideoTiming {
+    /// Show the watermark `offset_ms` milliseconds after the start of the video.
+    pub fn from_start(offset_ms: u64) -> Self {
+        InvideoTiming {
+            timing_type: TimingType::OffsetFromStart,
+            offset_ms: Some(offset_ms),
+            duration_ms: None,
+        }
+    }
+    /// Show the watermark `offset_ms` milliseconds before the end of the video.
+    pub fn from_end(offset_ms: u64) -> Self {
+        InvideoTiming {
+            timing_type: TimingType::OffsetFromEnd,
+            offset_ms: Some(offset_ms),
+            duration_ms: None,
+        }
+    }
+    /// Hide the watermark after `duration_ms` milliseconds.
+    pub fn with_duration(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TimingType {
+    #[default]
+    OffsetFromStart,
+    OffsetFromEnd,
+}
+
+/// Where the watermark is displayed in the player.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InvideoPosition {
+    #[serde(rename = "type")]
+    pub position_type: PositionType,
+    pub corner_position: CornerPosition,
+}
+
+impl InvideoPosition {
+    /// Display the watermark in a corner of the player.
+    pub fn corner(corner_position: CornerPosition) -> Self {
+        InvideoPosition {
+            position_type: PositionType::Corner,
+            corner_position,
+        }
+    }
+}
+
+/// Only corners are supported by the Api.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PositionType {
+    #[default]
+    Corner,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum CornerPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    #[test]
+    fn test_serialize_invideo_branding() {
+        let branding = InvideoBranding::new(
+            InvideoTiming::from_end(15000).with_duration(5000),
+            InvideoPosition::corner(CornerPosition::TopRight),
+        )
+        .with_target_channel_id("UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        assert_eq!(
+            serde_json::to_value(&branding).unwrap(),
+            json!({
+                "targetChannelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                "timing": {"type": "offsetFromEnd", "offsetMs": "15000", "durationMs": "5000"},
+                "position": {"type": "corner", "cornerPosition": "topRight"}
+            })
+        );
+    }
+    #[test]
+    fn test_deserialize_invideo_branding() {
+        let branding: InvideoBranding = serde_json::from_value(json!({
+            "imageUrl": "https://example.com/watermark.png",
+            "timing": {"type": "offsetFromStart", "offsetMs": "0"},
+            "position": {"type": "corner", "cornerPosition": "bottomLeft"}
+        }))
+        .unwrap();
+        assert_eq!(branding.timing, InvideoTiming::from_start(0));
+        assert_eq!(
+            branding.position.corner_position,
+            CornerPosition::BottomLeft
+        );
+    }
+}
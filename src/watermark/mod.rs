@@ -0,0 +1,117 @@
+use crate::client::{Client, ClientError};
+use crate::error::invalid_body;
+use crate::response::build_empty_response;
+use crate::transport::{HttpRequest, HttpTransport, Method};
+use crate::upload::multipart::MultipartRelated;
+use crate::upload::source::MediaSource;
+use crate::watermark::data::InvideoBranding;
+
+pub mod data;
+pub mod query;
+
+pub mod prelude {
+    pub use crate::watermark::{data::*, query::*, WatermarkEndPoint};
+}
+
+pub struct WatermarkEndPoint<'a, T> {
+    client: &'a Client<T>,
+    url: String,
+}
+
+impl<'a, T> Clone for WatermarkEndPoint<'a, T> {
+    fn clone(&self) -> Self {
+        WatermarkEndPoint {
+            client: self.client,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<'a, T: HttpTransport> WatermarkEndPoint<'a, T> {
+    /// Complete the url.
+    pub fn new(client: &'a Client<T>) -> Self {
+        let url = client.endpoint_url("watermarks/unset");
+        WatermarkEndPoint { client, url }
+    }
+
+    /// Uploads the watermark image of a channel and sets when and where it is displayed.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/watermarks/set .
+    pub async fn set<M: MediaSource>(
+        &self,
+        query_search: &str,
+        branding: &InvideoBranding,
+        mut image: M,
+    ) -> Result<(), ClientError> {
+        let url = format!(
+            "{}&{}",
+            self.client.upload_url("watermarks/set", "multipart"),
+            query_search
+        );
+        let bytes = image.read(0, image.size() as usize).await?;
+        let request = MultipartRelated::new()
+            .metadata(branding).map_err(invalid_body)?
+            .media(image.content_type(), &bytes)
+            .into_request(Method::Post, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+
+    /// Removes the watermark of a channel.
+    /// More information on the official documentation https://developers.google.com/youtube/v3/docs/watermarks/unset .
+    pub async fn unset(&self, query_search: &str) -> Result<(), ClientError> {
+        let url = format!("{}&{}", &self.url, query_search);
+        let request = HttpRequest::new(Method::Post, url);
+        build_empty_response(self.client.transport(), request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{json, MockTransport};
+    use crate::upload::source::BytesSource;
+    use crate::watermark::data::{CornerPosition, InvideoPosition, InvideoTiming};
+    use crate::watermark::query::WatermarkChannel;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_set_watermark() {
+        let transport = MockTransport::new().respond(Method::Post, "watermarks/set", json(204, ""));
+        let client = Client::with_transport("token", "key", transport);
+        let branding = InvideoBranding::new(
+            InvideoTiming::from_start(0),
+            InvideoPosition::corner(CornerPosition::BottomRight),
+        );
+        let image = BytesSource::new(b"PNG".to_vec(), "image/png");
+        let query = WatermarkChannel::new("UC_x5XG1OV2P6uZZ5FSM9Ttw").build_query_parameters();
+        block_on(
+            client
+                .watermarks()
+                .set(query.get_query_params(), &branding, image),
+        )
+        .unwrap();
+
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .starts_with("https://www.googleapis.com/upload/youtube/v3/watermarks/set?"));
+        assert!(request
+            .url
+            .contains("uploadType=multipart&channelId=UC_x5XG1OV2P6uZZ5FSM9Ttw"));
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+        assert!(body.contains(r#""position":{"type":"corner","cornerPosition":"bottomRight"}"#));
+        assert!(body.contains("Content-Type: image/png\r\n\r\nPNG\r\n"));
+    }
+    #[test]
+    fn test_unset_watermark() {
+        let transport =
+            MockTransport::new().respond(Method::Post, "watermarks/unset", json(204, ""));
+        let client = Client::with_transport("token", "key", transport);
+        let query = WatermarkChannel::new("UC_x5XG1OV2P6uZZ5FSM9Ttw").build_query_parameters();
+        block_on(client.watermarks().unset(query.get_query_params())).unwrap();
+        let request = client.transport().last_request().unwrap();
+        assert!(request
+            .url
+            .starts_with("https://www.googleapis.com/youtube/v3/watermarks/unset?"));
+        assert!(request.body.is_none());
+    }
+}
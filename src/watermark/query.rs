@@ -0,0 +1,61 @@
+use crate::query::QueryString;
+use serde::{Deserialize, Serialize};
+
+/// Query of `watermarks.set` and `watermarks.unset`, both target a channel.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct WatermarkChannel {
+    /// ID of the channel the watermark is set for or removed from
+    channel_id: String,
+    /// Content owner the request is made for, only for YouTube content partners
+    on_behalf_of_content_owner: Option<String>,
+    /// Query parameters to be used in URL
+    query_params: String,
+}
+
+impl WatermarkChannel {
+    pub fn new(channel_id: &str) -> WatermarkChannel {
+        WatermarkChannel::default().parse_channel_id(channel_id)
+    }
+    /// Parse channel_id to its field
+    pub fn parse_channel_id(mut self, channel_id: &str) -> Self {
+        self.channel_id = channel_id.to_string();
+        self
+    }
+    /// Parse on_behalf_of_content_owner to its field
+    pub fn parse_on_behalf_of_content_owner(mut self, content_owner: &str) -> Self {
+        self.on_behalf_of_content_owner = Some(content_owner.to_string());
+        self
+    }
+    /// Returns the channel id
+    pub fn channel_id(&self) -> &str {
+        &self.channel_id
+    }
+    /// Build and assign the query parameters
+    pub fn build_query_parameters(mut self) -> Self {
+        self.query_params = QueryString::new()
+            .push("channelId", &self.channel_id)
+            .push_opt(
+                "onBehalfOfContentOwner",
+                self.on_behalf_of_content_owner.as_ref(),
+            )
+            .to_string();
+        self
+    }
+    /// Returns query parameters
+    pub fn get_query_params(&self) -> &str {
+        &self.query_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_build_query_params_watermark_channel() {
+        let watermark = WatermarkChannel::new("UC_x5XG1OV2P6uZZ5FSM9Ttw").build_query_parameters();
+        assert_eq!(
+            watermark.get_query_params(),
+            "channelId=UC_x5XG1OV2P6uZZ5FSM9Ttw"
+        )
+    }
+}